@@ -0,0 +1,273 @@
+//! Compact binary encoding of a Stack with `to_bytes()` and `from_bytes()`.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::{Pointer, Stack};
+
+/// Size in bytes of the length prefix written before the values.
+const LENGTH_SIZE: usize = size_of::<u64>();
+
+/// Value with a fixed-width little-endian binary representation.
+///
+/// ```
+/// use solanum::BinaryValue;
+///
+/// let mut bytes = Vec::new();
+/// 0x0102_u16.write_bytes(&mut bytes);
+///
+/// assert_eq!(bytes, vec![0x02, 0x01]);
+/// assert_eq!(u16::read_bytes(&bytes), 0x0102);
+/// ```
+pub trait BinaryValue: Sized {
+    /// Number of bytes taken by every encoded value.
+    ///
+    /// It must be greater than zero, as [Stack::from_bytes] reads values in chunks of this size.
+    /// Calling it for a type with a zero `SIZE` fails to compile.
+    ///
+    /// ```compile_fail
+    /// use solanum::{BinaryValue, Stack};
+    ///
+    /// #[derive(Clone)]
+    /// struct Unit;
+    ///
+    /// impl BinaryValue for Unit {
+    ///     const SIZE: usize = 0;
+    ///     fn write_bytes(&self, _out: &mut Vec<u8>) {}
+    ///     fn read_bytes(_bytes: &[u8]) -> Self {
+    ///         Unit
+    ///     }
+    /// }
+    ///
+    /// let _ = Stack::<Unit>::from_bytes(&[0; 8]);
+    /// ```
+    const SIZE: usize;
+
+    /// Append the little-endian bytes of the value to `out`.
+    fn write_bytes(&self, out: &mut Vec<u8>);
+
+    /// Read a value from exactly [BinaryValue::SIZE] little-endian bytes.
+    ///
+    /// [Stack::from_bytes] checks the input length before reading, so it only ever passes
+    /// slices of the right length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is not exactly [BinaryValue::SIZE] bytes long.
+    fn read_bytes(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_binary_value {
+    ($($value_type:ty),*) => {
+        $(
+            impl BinaryValue for $value_type {
+                const SIZE: usize = size_of::<$value_type>();
+
+                fn write_bytes(&self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn read_bytes(bytes: &[u8]) -> Self {
+                    let bytes = bytes.try_into().unwrap_or_else(|_| {
+                        panic!(
+                            "expected {} bytes but found {}",
+                            Self::SIZE,
+                            bytes.len()
+                        )
+                    });
+                    <$value_type>::from_le_bytes(bytes)
+                }
+            }
+        )*
+    };
+}
+
+impl_binary_value!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+/// Error returned when bytes do not hold a valid encoded Stack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodeError {
+    /// Number of bytes the encoding should take.
+    pub expected: usize,
+    /// Number of bytes that were given.
+    pub actual: usize,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected {} bytes but found {}",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl Error for DecodeError {}
+
+impl<T: Clone + BinaryValue, P: Pointer> Stack<T, P> {
+    /// Encode the Stack as its size followed by its values, starting from the head.
+    ///
+    /// The size is written as a little-endian [u64], and every value with
+    /// [BinaryValue::write_bytes].
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push(1_u8);
+    /// stack.push(2_u8);
+    ///
+    /// assert_eq!(stack.to_bytes(), vec![2, 0, 0, 0, 0, 0, 0, 0, 2, 1]);
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let size = self.len();
+        let mut bytes = Vec::with_capacity(LENGTH_SIZE + size * T::SIZE);
+        bytes.extend_from_slice(&(size as u64).to_le_bytes());
+        for value in self.iter() {
+            value.write_bytes(&mut bytes);
+        }
+        bytes
+    }
+
+    /// Decode a Stack written by [Stack::to_bytes].
+    ///
+    /// Returns [DecodeError] if `bytes` is shorter or longer than the encoded size says.
+    ///
+    /// ```
+    /// # use solanum::{DecodeError, Stack};
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![1_u32, 2, 3]);
+    ///
+    /// let bytes = stack.to_bytes();
+    /// let decoded = Stack::<u32>::from_bytes(&bytes).unwrap();
+    /// assert_eq!(decoded.to_list(), vec![3, 2, 1]);
+    ///
+    /// let error = Stack::<u32>::from_bytes(&bytes[..bytes.len() - 1]).err();
+    /// assert_eq!(error, Some(DecodeError { expected: 20, actual: 19 }));
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Stack<T, P>, DecodeError> {
+        const { assert!(T::SIZE > 0, "BinaryValue::SIZE must be greater than zero") };
+        let Some((size, values)) = bytes.split_first_chunk::<LENGTH_SIZE>() else {
+            return Err(DecodeError {
+                expected: LENGTH_SIZE,
+                actual: bytes.len(),
+            });
+        };
+        let expected = usize::try_from(u64::from_le_bytes(*size))
+            .ok()
+            .and_then(|size| size.checked_mul(T::SIZE))
+            .and_then(|size| size.checked_add(LENGTH_SIZE))
+            .unwrap_or(usize::MAX);
+        if bytes.len() != expected {
+            return Err(DecodeError {
+                expected,
+                actual: bytes.len(),
+            });
+        }
+        let list = values.chunks_exact(T::SIZE).map(T::read_bytes).collect();
+        Ok(Stack::from_list(list))
+    }
+}
+
+#[cfg(test)]
+mod binary_value_tests {
+    use super::*;
+
+    #[test]
+    fn binary_value_round_trip() {
+        let mut bytes = Vec::new();
+        u32::MAX.write_bytes(&mut bytes);
+        (-2_i16).write_bytes(&mut bytes);
+        assert_eq!(bytes.len(), u32::SIZE + i16::SIZE);
+        assert_eq!(u32::read_bytes(&bytes[..4]), u32::MAX);
+        assert_eq!(i16::read_bytes(&bytes[4..]), -2);
+    }
+
+    #[test]
+    fn binary_value_is_little_endian() {
+        let mut bytes = Vec::new();
+        0x0102_0304_u32.write_bytes(&mut bytes);
+        assert_eq!(bytes, vec![0x04, 0x03, 0x02, 0x01]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected 4 bytes but found 3")]
+    fn read_bytes_with_wrong_length() {
+        u32::read_bytes(&[1, 2, 3]);
+    }
+}
+
+#[cfg(test)]
+mod stack_bytes_tests {
+    use super::*;
+
+    #[test]
+    fn empty_stack_round_trip() {
+        let stack: Stack<u32> = Stack::empty();
+        let bytes = stack.to_bytes();
+        assert_eq!(bytes, vec![0; LENGTH_SIZE]);
+        assert!(Stack::<u32>::from_bytes(&bytes).unwrap().is_empty());
+    }
+
+    #[test]
+    fn filled_stack_round_trip() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1_u32, 2, u32::MAX]);
+        let bytes = stack.to_bytes();
+        assert_eq!(bytes.len(), LENGTH_SIZE + 3 * 4);
+
+        let decoded = Stack::<u32>::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.to_list(), vec![u32::MAX, 2, 1]);
+    }
+
+    #[test]
+    fn to_bytes_writes_values_from_head() {
+        let mut stack = Stack::empty();
+        stack.push(1_u32);
+        stack.push(2_u32);
+        assert_eq!(stack.to_bytes()[LENGTH_SIZE..], [2, 0, 0, 0, 1, 0, 0, 0]);
+    }
+
+    #[test]
+    fn from_bytes_with_truncated_length() {
+        let error = Stack::<u32>::from_bytes(&[1, 0, 0]).err().unwrap();
+        assert_eq!(
+            error,
+            DecodeError {
+                expected: LENGTH_SIZE,
+                actual: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn from_bytes_with_truncated_values() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1_u32, 2]);
+        let bytes = stack.to_bytes();
+
+        let error = Stack::<u32>::from_bytes(&bytes[..bytes.len() - 2])
+            .err()
+            .unwrap();
+        assert_eq!(error.expected, 16);
+        assert_eq!(error.actual, 14);
+        assert_eq!(error.to_string(), "expected 16 bytes but found 14");
+    }
+
+    #[test]
+    fn from_bytes_with_trailing_bytes() {
+        let mut bytes = Stack::new(1_u8).to_bytes();
+        bytes.push(0);
+        let error = Stack::<u8>::from_bytes(&bytes).err().unwrap();
+        assert_eq!(error.expected, 9);
+        assert_eq!(error.actual, 10);
+    }
+
+    #[test]
+    fn from_bytes_with_oversized_length() {
+        let bytes = u64::MAX.to_le_bytes();
+        let error = Stack::<u32>::from_bytes(&bytes).err().unwrap();
+        assert_eq!(error.expected, usize::MAX);
+        assert_eq!(error.actual, LENGTH_SIZE);
+    }
+}
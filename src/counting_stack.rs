@@ -0,0 +1,235 @@
+//! Implementation of a Stack wrapper counting its `push()` and `pop()` calls.
+
+use crate::Stack;
+
+/// Implementation of a Stack that records how many values were pushed and popped over its lifetime.
+///
+///
+/// Examples:
+///
+/// ```
+/// use solanum::CountingStack;
+///
+/// let mut stack = CountingStack::empty();
+/// stack.push(100);
+/// stack.push(200);
+/// stack.pop();
+/// stack.push(300);
+///
+/// assert_eq!(stack.push_count(), 3);
+/// assert_eq!(stack.pop_count(), 1);
+/// assert_eq!(stack.to_list(), vec![300, 100]);
+/// ```
+pub struct CountingStack<T> {
+    stack: Stack<T>,
+    push_count: usize,
+    pop_count: usize,
+}
+
+impl<T: Clone> CountingStack<T> {
+    /// Create an empty CountingStack.
+    ///
+    /// ```
+    /// # use solanum::CountingStack;
+    /// let stack: CountingStack<u32> = CountingStack::empty();
+    ///
+    /// assert_eq!(stack.len(), 0);
+    /// assert_eq!(stack.push_count(), 0);
+    /// ```
+    pub fn empty() -> CountingStack<T> {
+        Self {
+            stack: Stack::empty(),
+            push_count: 0,
+            pop_count: 0,
+        }
+    }
+
+    /// Create a CountingStack with single value, which counts as one push.
+    ///
+    /// ```
+    /// # use solanum::CountingStack;
+    /// let stack = CountingStack::new(100);
+    ///
+    /// assert_eq!(stack.len(), 1);
+    /// assert_eq!(stack.push_count(), 1);
+    /// ```
+    pub fn new(value: T) -> CountingStack<T> {
+        Self {
+            stack: Stack::new(value),
+            push_count: 1,
+            pop_count: 0,
+        }
+    }
+
+    /// Return the number of values in the CountingStack.
+    ///
+    /// ```
+    /// # use solanum::CountingStack;
+    /// let stack = CountingStack::new(100);
+    /// assert_eq!(stack.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// Check if CountingStack is empty.
+    ///
+    /// ```
+    /// # use solanum::CountingStack;
+    /// let stack: CountingStack<u32> = CountingStack::empty();
+    ///
+    /// assert!(stack.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    /// Return the head value without removing it from the CountingStack.
+    ///
+    /// ```
+    /// # use solanum::CountingStack;
+    /// let stack = CountingStack::new(1000);
+    /// assert_eq!(stack.peek(), Some(1000));
+    /// ```
+    pub fn peek(&self) -> Option<T> {
+        self.stack.peek()
+    }
+
+    /// Insert a value into and place it on the head of the CountingStack, counting the push.
+    ///
+    /// ```
+    /// # use solanum::CountingStack;
+    /// let mut stack = CountingStack::empty();
+    /// stack.push(100);
+    ///
+    /// assert_eq!(stack.peek(), Some(100));
+    /// assert_eq!(stack.push_count(), 1);
+    /// ```
+    pub fn push(&mut self, value: T) {
+        self.stack.push(value);
+        self.push_count += 1;
+    }
+
+    /// Pop the head value of the CountingStack, counting the pop only if a value was removed.
+    ///
+    /// ```
+    /// # use solanum::CountingStack;
+    /// let mut stack = CountingStack::new(100);
+    ///
+    /// assert_eq!(stack.pop(), Some(100));
+    /// assert_eq!(stack.pop(), None);
+    /// assert_eq!(stack.pop_count(), 1);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        let value = self.stack.pop()?;
+        self.pop_count += 1;
+        Some(value)
+    }
+
+    /// Traverse the CountingStack and return all values as [Vec], starting from the head.
+    ///
+    /// ```
+    /// # use solanum::CountingStack;
+    /// let mut stack = CountingStack::empty();
+    /// stack.push(1000);
+    /// stack.push(2000);
+    ///
+    /// assert_eq!(stack.to_list(), vec![2000, 1000]);
+    /// ```
+    pub fn to_list(&self) -> Vec<T> {
+        self.stack.to_list()
+    }
+
+    /// Return how many values have been pushed over the lifetime of the CountingStack.
+    ///
+    /// ```
+    /// # use solanum::CountingStack;
+    /// let mut stack = CountingStack::empty();
+    /// stack.push(100);
+    /// stack.push(200);
+    ///
+    /// assert_eq!(stack.push_count(), 2);
+    /// ```
+    pub fn push_count(&self) -> usize {
+        self.push_count
+    }
+
+    /// Return how many values have been popped over the lifetime of the CountingStack.
+    ///
+    /// Popping an empty CountingStack is not counted.
+    ///
+    /// ```
+    /// # use solanum::CountingStack;
+    /// let mut stack = CountingStack::new(100);
+    /// stack.pop();
+    /// stack.pop();
+    ///
+    /// assert_eq!(stack.pop_count(), 1);
+    /// ```
+    pub fn pop_count(&self) -> usize {
+        self.pop_count
+    }
+}
+
+#[cfg(test)]
+mod create_tests {
+    use super::*;
+
+    #[test]
+    fn create_counting_stack_with_empty() {
+        let stack: CountingStack<u32> = CountingStack::empty();
+        assert!(stack.is_empty());
+        assert_eq!(stack.push_count(), 0);
+        assert_eq!(stack.pop_count(), 0);
+    }
+
+    #[test]
+    fn create_counting_stack_with_new() {
+        let stack = CountingStack::new(1);
+        assert_eq!(stack.peek(), Some(1));
+        assert_eq!(stack.push_count(), 1);
+        assert_eq!(stack.pop_count(), 0);
+    }
+}
+
+#[cfg(test)]
+mod count_tests {
+    use super::*;
+
+    #[test]
+    fn push_increments_push_count() {
+        let mut stack = CountingStack::empty();
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+        assert_eq!(stack.push_count(), 3);
+        assert_eq!(stack.pop_count(), 0);
+        assert_eq!(stack.to_list(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn pop_on_empty_stack_is_not_counted() {
+        let mut stack: CountingStack<u32> = CountingStack::empty();
+        assert_eq!(stack.pop(), None);
+        assert_eq!(stack.pop(), None);
+        assert_eq!(stack.pop_count(), 0);
+    }
+
+    #[test]
+    fn counts_across_a_sequence_of_operations() {
+        let mut stack = CountingStack::empty();
+        stack.push(1);
+        stack.push(2);
+        assert_eq!(stack.pop(), Some(2));
+        stack.push(3);
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), Some(1));
+        assert_eq!(stack.pop(), None);
+        stack.push(4);
+
+        assert_eq!(stack.push_count(), 4);
+        assert_eq!(stack.pop_count(), 3);
+        assert_eq!(stack.len(), 1);
+        assert_eq!(stack.to_list(), vec![4]);
+    }
+}
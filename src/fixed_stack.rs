@@ -0,0 +1,458 @@
+//! Implementation of a fixed-capacity Stack stored inline, without heap allocation.
+
+use std::error::Error;
+use std::fmt;
+use std::mem::MaybeUninit;
+
+/// Implementation of a Stack holding up to `N` values in an inline array.
+///
+///
+/// Examples:
+///
+/// ```
+/// use solanum::FixedStack;
+///
+/// let mut stack: FixedStack<u32, 2> = FixedStack::empty();
+/// assert_eq!(stack.push(100), Ok(()));
+/// assert_eq!(stack.push(200), Ok(()));
+/// assert_eq!(stack.push(300), Err(300));
+///
+/// assert_eq!(stack.len(), 2);
+/// assert_eq!(stack.pop(), Some(200));
+/// assert_eq!(stack.peek(), Some(&100));
+/// ```
+pub struct FixedStack<T, const N: usize> {
+    // only the first `len` values are initialized, the head being at `len - 1`
+    values: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> FixedStack<T, N> {
+    /// Create an empty FixedStack.
+    ///
+    /// ```
+    /// # use solanum::FixedStack;
+    /// let stack: FixedStack<u32, 4> = FixedStack::empty();
+    ///
+    /// assert_eq!(stack.len(), 0);
+    /// ```
+    pub fn empty() -> FixedStack<T, N> {
+        Self {
+            values: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        }
+    }
+
+    /// Return the number of values in the FixedStack.
+    ///
+    /// ```
+    /// # use solanum::FixedStack;
+    /// let mut stack: FixedStack<u32, 4> = FixedStack::empty();
+    /// stack.push(100).unwrap();
+    ///
+    /// assert_eq!(stack.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return the maximum number of values the FixedStack can hold, which is `N`.
+    ///
+    /// ```
+    /// # use solanum::FixedStack;
+    /// let stack: FixedStack<u32, 4> = FixedStack::empty();
+    ///
+    /// assert_eq!(stack.capacity(), 4);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Check if FixedStack is empty.
+    ///
+    /// ```
+    /// # use solanum::FixedStack;
+    /// let stack: FixedStack<u32, 4> = FixedStack::empty();
+    ///
+    /// assert!(stack.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Check if FixedStack is full, holding `N` values.
+    ///
+    /// ```
+    /// # use solanum::FixedStack;
+    /// let mut stack: FixedStack<u32, 1> = FixedStack::empty();
+    /// assert!(!stack.is_full());
+    ///
+    /// stack.push(100).unwrap();
+    /// assert!(stack.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Return a reference to the head value without removing it from the FixedStack.
+    ///
+    /// ```
+    /// # use solanum::FixedStack;
+    /// let mut stack: FixedStack<u32, 4> = FixedStack::empty();
+    /// assert_eq!(stack.peek(), None);
+    ///
+    /// stack.push(100).unwrap();
+    /// assert_eq!(stack.peek(), Some(&100));
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        if self.is_empty() {
+            None
+        } else {
+            // SAFETY: values below `len` are initialized.
+            Some(unsafe { self.values[self.len - 1].assume_init_ref() })
+        }
+    }
+
+    /// Insert a value into and place it on the head of the FixedStack.
+    ///
+    /// Returns the value back in [Err] if the FixedStack is already full.
+    ///
+    /// ```
+    /// # use solanum::FixedStack;
+    /// let mut stack: FixedStack<u32, 1> = FixedStack::empty();
+    ///
+    /// assert_eq!(stack.push(100), Ok(()));
+    /// assert_eq!(stack.push(200), Err(200));
+    /// ```
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+        self.values[self.len].write(value);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Pop the head value of the FixedStack.
+    ///
+    /// Returns [Some] if value exists, or [None] if stack is already empty.
+    ///
+    /// ```
+    /// # use solanum::FixedStack;
+    /// let mut stack: FixedStack<u32, 4> = FixedStack::empty();
+    /// stack.push(100).unwrap();
+    ///
+    /// assert_eq!(stack.pop(), Some(100));
+    /// assert_eq!(stack.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            None
+        } else {
+            self.len -= 1;
+            // SAFETY: the value at the old head was initialized, and is no longer counted in
+            // `len`, so it is not read or dropped again.
+            Some(unsafe { self.values[self.len].assume_init_read() })
+        }
+    }
+}
+
+impl<T: Clone, const N: usize> FixedStack<T, N> {
+    /// Traverse the FixedStack and return all values as [Vec], starting from the head.
+    ///
+    /// ```
+    /// # use solanum::FixedStack;
+    /// let mut stack: FixedStack<u32, 4> = FixedStack::empty();
+    /// stack.push(1000).unwrap();
+    /// stack.push(2000).unwrap();
+    ///
+    /// assert_eq!(stack.to_list(), vec![2000, 1000]);
+    /// ```
+    pub fn to_list(&self) -> Vec<T> {
+        self.values[..self.len]
+            .iter()
+            .rev()
+            // SAFETY: values below `len` are initialized.
+            .map(|value| unsafe { value.assume_init_ref() }.clone())
+            .collect()
+    }
+}
+
+impl<T, const N: usize> Drop for FixedStack<T, N> {
+    fn drop(&mut self) {
+        for value in &mut self.values[..self.len] {
+            // SAFETY: values below `len` are initialized, and are dropped only once here.
+            unsafe { value.assume_init_drop() };
+        }
+    }
+}
+
+impl<T, const N: usize> TryFrom<Vec<T>> for FixedStack<T, N> {
+    type Error = CapacityExceeded;
+
+    /// Create a FixedStack by pushing every value of the [Vec] in order, so the last value ends up
+    /// on the head.
+    ///
+    /// Returns [CapacityExceeded] if the [Vec] holds more than `N` values.
+    ///
+    /// ```
+    /// # use solanum::{CapacityExceeded, FixedStack};
+    /// let stack = FixedStack::<u32, 3>::try_from(vec![1, 2, 3]).unwrap();
+    /// assert_eq!(stack.to_list(), vec![3, 2, 1]);
+    ///
+    /// let result = FixedStack::<u32, 3>::try_from(vec![1, 2, 3, 4, 5]);
+    /// assert_eq!(result.err(), Some(CapacityExceeded { overflow: 2 }));
+    /// ```
+    fn try_from(values: Vec<T>) -> Result<Self, Self::Error> {
+        if values.len() > N {
+            return Err(CapacityExceeded {
+                overflow: values.len() - N,
+            });
+        }
+        let mut stack = FixedStack::empty();
+        for value in values {
+            // cannot fail, as the length has been checked above
+            let _ = stack.push(value);
+        }
+        Ok(stack)
+    }
+}
+
+/// Error returned when converting more values than a FixedStack can hold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityExceeded {
+    /// Number of values over the capacity of the FixedStack.
+    pub overflow: usize,
+}
+
+impl fmt::Display for CapacityExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.overflow == 1 {
+            write!(f, "capacity exceeded by 1 value")
+        } else {
+            write!(f, "capacity exceeded by {} values", self.overflow)
+        }
+    }
+}
+
+impl Error for CapacityExceeded {}
+
+#[cfg(test)]
+mod create_tests {
+    use super::*;
+
+    #[test]
+    fn create_fixed_stack_with_empty() {
+        let stack: FixedStack<u32, 3> = FixedStack::empty();
+        assert!(stack.is_empty());
+        assert_eq!(stack.len(), 0);
+        assert_eq!(stack.capacity(), 3);
+        assert_eq!(stack.peek(), None);
+    }
+
+    #[test]
+    fn zero_capacity_fixed_stack() {
+        let mut stack: FixedStack<u32, 0> = FixedStack::empty();
+        assert_eq!(stack.push(1), Err(1));
+        assert_eq!(stack.pop(), None);
+    }
+}
+
+#[cfg(test)]
+mod is_full_tests {
+    use super::*;
+
+    #[test]
+    fn is_full_below_capacity() {
+        let mut stack: FixedStack<u32, 3> = FixedStack::empty();
+        assert!(!stack.is_full());
+        stack.push(1).unwrap();
+        stack.push(2).unwrap();
+        assert!(!stack.is_full());
+    }
+
+    #[test]
+    fn is_full_at_capacity() {
+        let mut stack: FixedStack<u32, 2> = FixedStack::empty();
+        stack.push(1).unwrap();
+        stack.push(2).unwrap();
+        assert!(stack.is_full());
+        assert_eq!(stack.push(3), Err(3));
+    }
+
+    #[test]
+    fn is_full_after_pop() {
+        let mut stack: FixedStack<u32, 1> = FixedStack::empty();
+        stack.push(1).unwrap();
+        stack.pop();
+        assert!(!stack.is_full());
+    }
+
+    #[test]
+    fn zero_capacity_fixed_stack_is_full() {
+        let stack: FixedStack<u32, 0> = FixedStack::empty();
+        assert!(stack.is_full());
+        assert!(stack.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod push_tests {
+    use super::*;
+
+    #[test]
+    fn push_to_capacity() {
+        let mut stack: FixedStack<u32, 3> = FixedStack::empty();
+        assert_eq!(stack.push(1), Ok(()));
+        assert_eq!(stack.push(2), Ok(()));
+        assert_eq!(stack.push(3), Ok(()));
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.to_list(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn push_over_capacity_is_rejected() {
+        let mut stack: FixedStack<u32, 2> = FixedStack::empty();
+        stack.push(1).unwrap();
+        stack.push(2).unwrap();
+        assert_eq!(stack.push(3), Err(3));
+        assert_eq!(stack.len(), 2);
+        assert_eq!(stack.to_list(), vec![2, 1]);
+    }
+
+    #[test]
+    fn push_after_pop_on_full_stack() {
+        let mut stack: FixedStack<u32, 2> = FixedStack::empty();
+        stack.push(1).unwrap();
+        stack.push(2).unwrap();
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.push(3), Ok(()));
+        assert_eq!(stack.to_list(), vec![3, 1]);
+    }
+}
+
+#[cfg(test)]
+mod pop_tests {
+    use super::*;
+
+    #[test]
+    fn pop_on_empty_stack() {
+        let mut stack: FixedStack<u32, 2> = FixedStack::empty();
+        assert_eq!(stack.pop(), None);
+    }
+
+    #[test]
+    fn pop_in_reverse_push_order() {
+        let mut stack: FixedStack<u32, 3> = FixedStack::empty();
+        stack.push(1).unwrap();
+        stack.push(2).unwrap();
+        stack.push(3).unwrap();
+
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.peek(), Some(&2));
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.pop(), Some(1));
+        assert_eq!(stack.pop(), None);
+        assert!(stack.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod drop_tests {
+    use super::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn drop_remaining_values() {
+        let value = Rc::new(1);
+        {
+            let mut stack: FixedStack<Rc<u32>, 4> = FixedStack::empty();
+            stack.push(Rc::clone(&value)).unwrap();
+            stack.push(Rc::clone(&value)).unwrap();
+            stack.push(Rc::clone(&value)).unwrap();
+            assert_eq!(Rc::strong_count(&value), 4); // value itself, and 3 in the stack
+        }
+        // stack is destroyed here
+
+        assert_eq!(Rc::strong_count(&value), 1); // value itself
+    }
+
+    #[test]
+    fn popped_values_are_not_dropped_again() {
+        let value = Rc::new(1);
+        let popped;
+        {
+            let mut stack: FixedStack<Rc<u32>, 4> = FixedStack::empty();
+            stack.push(Rc::clone(&value)).unwrap();
+            stack.push(Rc::clone(&value)).unwrap();
+            popped = stack.pop().unwrap();
+            assert_eq!(Rc::strong_count(&value), 3); // value itself, popped, and 1 in the stack
+        }
+        // stack is destroyed here, dropping only the value still in it
+
+        assert_eq!(Rc::strong_count(&value), 2); // value itself, and popped
+        drop(popped);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn rejected_value_is_returned_not_leaked() {
+        let value = Rc::new(1);
+        {
+            let mut stack: FixedStack<Rc<u32>, 1> = FixedStack::empty();
+            stack.push(Rc::clone(&value)).unwrap();
+            let rejected = stack.push(Rc::clone(&value)).unwrap_err();
+            assert_eq!(Rc::strong_count(&value), 3);
+            drop(rejected);
+        }
+
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+}
+
+#[cfg(test)]
+mod try_from_tests {
+    use super::*;
+
+    #[test]
+    fn try_from_empty_vec() {
+        let stack = FixedStack::<u32, 2>::try_from(Vec::new()).unwrap();
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn try_from_fitting_vec() {
+        let stack = FixedStack::<u32, 4>::try_from(vec![1, 2, 3]).unwrap();
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.to_list(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn try_from_vec_at_capacity() {
+        let stack = FixedStack::<u32, 3>::try_from(vec![1, 2, 3]).unwrap();
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.peek(), Some(&3));
+    }
+
+    #[test]
+    fn try_from_oversized_vec() {
+        let result = FixedStack::<u32, 2>::try_from(vec![1, 2, 3, 4, 5]);
+        let error = result.err().unwrap();
+        assert_eq!(error, CapacityExceeded { overflow: 3 });
+        assert_eq!(error.to_string(), "capacity exceeded by 3 values");
+    }
+
+    #[test]
+    fn capacity_exceeded_is_error() {
+        fn convert(values: Vec<u32>) -> Result<usize, Box<dyn Error>> {
+            let stack = FixedStack::<u32, 1>::try_from(values)?;
+            Ok(stack.len())
+        }
+
+        assert_eq!(convert(vec![1]).unwrap(), 1);
+        assert_eq!(
+            convert(vec![1, 2]).unwrap_err().to_string(),
+            "capacity exceeded by 1 value"
+        );
+    }
+}
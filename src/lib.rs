@@ -2,6 +2,16 @@
 
 //! A collection of memory-safe linear data structure
 
+pub mod binary;
+pub mod counting_stack;
+pub mod fixed_stack;
+pub mod list;
+pub mod pointer;
 pub mod stack;
 
-pub use stack::Stack;
+pub use binary::{BinaryValue, DecodeError};
+pub use counting_stack::CountingStack;
+pub use fixed_stack::{CapacityExceeded, FixedStack};
+pub use list::List;
+pub use pointer::Pointer;
+pub use stack::{EmptyStackError, Stack, StackSnapshot, StackStats};
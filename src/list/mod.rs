@@ -0,0 +1,447 @@
+//! Implementation of a singly linked List with `push_front()`, `push_back()` and `splice()`.
+
+mod node;
+
+use std::rc::Rc;
+
+use node::{Link, MutableNode};
+
+/// Implementation of a singly linked List, that can be grown from both ends.
+///
+/// The List keeps a link to its tail node and its length, so [List::push_back], [List::splice]
+/// and [List::len] all take O(1) time.
+///
+/// Examples:
+///
+/// ```
+/// use solanum::List;
+///
+/// let mut list = List::empty();
+/// list.push_back(200);
+/// list.push_front(100);
+/// list.push_back(300);
+/// list.pop_front();
+///
+/// assert_eq!(list.len(), 2);
+/// assert_eq!(list.front(), Some(200));
+/// assert_eq!(list.to_list(), vec![200, 300]);
+/// ```
+pub struct List<T> {
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+}
+
+impl<T: Clone> List<T> {
+    /// Create an empty List.
+    ///
+    /// ```
+    /// # use solanum::List;
+    /// let list: List<u32> = List::empty();
+    ///
+    /// assert_eq!(list.len(), 0);
+    /// ```
+    pub fn empty() -> List<T> {
+        Self {
+            head: None,
+            tail: None,
+            len: 0,
+        }
+    }
+
+    /// Create a List with single value.
+    ///
+    /// ```
+    /// # use solanum::List;
+    /// let list = List::new(100);
+    ///
+    /// assert_eq!(list.len(), 1);
+    /// ```
+    pub fn new(value: T) -> List<T> {
+        let mut list = List::empty();
+        list.push_back(value);
+        list
+    }
+
+    /// Return the number of values in the List.
+    ///
+    /// The length is cached, so this takes O(1) time.
+    ///
+    /// ```
+    /// # use solanum::List;
+    /// let mut list = List::new(100);
+    /// list.push_back(200);
+    ///
+    /// assert_eq!(list.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if the List is empty.
+    ///
+    /// ```
+    /// # use solanum::List;
+    /// let list: List<u32> = List::empty();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Add a value before the front of the List.
+    ///
+    /// ```
+    /// # use solanum::List;
+    /// let mut list = List::new(200);
+    /// list.push_front(100);
+    ///
+    /// assert_eq!(list.to_list(), vec![100, 200]);
+    /// ```
+    pub fn push_front(&mut self, value: T) {
+        let node = MutableNode::new(value);
+        match self.head.take() {
+            None => self.tail = Some(Rc::clone(&node)),
+            Some(head_node) => node.borrow_mut().next = Some(head_node),
+        }
+        self.head = Some(node);
+        self.len += 1;
+    }
+
+    /// Add a value after the back of the List.
+    ///
+    /// ```
+    /// # use solanum::List;
+    /// let mut list = List::new(100);
+    /// list.push_back(200);
+    ///
+    /// assert_eq!(list.to_list(), vec![100, 200]);
+    /// ```
+    pub fn push_back(&mut self, value: T) {
+        let node = MutableNode::new(value);
+        match self.tail.take() {
+            None => self.head = Some(Rc::clone(&node)),
+            Some(tail_node) => tail_node.borrow_mut().next = Some(Rc::clone(&node)),
+        }
+        self.tail = Some(node);
+        self.len += 1;
+    }
+
+    /// Pop the front value of the List.
+    ///
+    /// Returns [Some] if value exists, or [None] if list is already empty.
+    ///
+    /// ```
+    /// # use solanum::List;
+    /// let mut list = List::new(100);
+    ///
+    /// assert_eq!(list.pop_front(), Some(100));
+    /// assert_eq!(list.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        let head_node = self.head.take()?;
+        self.head = head_node.borrow_mut().next.take();
+        if self.head.is_none() {
+            self.tail = None;
+        }
+        self.len -= 1;
+        let head_node = Rc::try_unwrap(head_node)
+            .ok()
+            .expect("popped node is only linked by the list");
+        Some(head_node.into_inner().value)
+    }
+
+    /// Return the front value of the List, or [None] if the List is empty.
+    ///
+    /// ```
+    /// # use solanum::List;
+    /// let mut list = List::new(100);
+    /// list.push_back(200);
+    ///
+    /// assert_eq!(list.front(), Some(100));
+    /// ```
+    pub fn front(&self) -> Option<T> {
+        self.head.as_ref().map(|node| node.borrow().value.clone())
+    }
+
+    /// Return the back value of the List, or [None] if the List is empty.
+    ///
+    /// ```
+    /// # use solanum::List;
+    /// let mut list = List::new(100);
+    /// list.push_back(200);
+    ///
+    /// assert_eq!(list.back(), Some(200));
+    /// ```
+    pub fn back(&self) -> Option<T> {
+        self.tail.as_ref().map(|node| node.borrow().value.clone())
+    }
+
+    /// Move every value of `other` after the back of the List, keeping their order.
+    ///
+    /// The nodes of `other` are relinked rather than copied, so this takes O(1) time whatever the
+    /// length of either List.
+    ///
+    /// ```
+    /// # use solanum::List;
+    /// let mut list = List::new(100);
+    /// let mut other = List::new(200);
+    /// other.push_back(300);
+    ///
+    /// list.splice(other);
+    /// assert_eq!(list.len(), 3);
+    /// assert_eq!(list.to_list(), vec![100, 200, 300]);
+    /// ```
+    pub fn splice(&mut self, mut other: List<T>) {
+        let Some(other_head) = other.head.take() else {
+            return;
+        };
+        match self.tail.take() {
+            None => self.head = Some(other_head),
+            Some(tail_node) => tail_node.borrow_mut().next = Some(other_head),
+        }
+        self.tail = other.tail.take();
+        self.len += other.len;
+    }
+
+    /// Traverse the List and return all values as [Vec], starting from the front.
+    ///
+    /// ```
+    /// # use solanum::List;
+    /// let mut list = List::new(100);
+    /// list.push_back(200);
+    ///
+    /// assert_eq!(list.to_list(), vec![100, 200]);
+    /// ```
+    pub fn to_list(&self) -> Vec<T> {
+        let mut list = Vec::with_capacity(self.len);
+        let mut node_pointer = self.head.clone();
+        while let Some(node) = node_pointer {
+            let node = node.borrow();
+            list.push(node.value.clone());
+            node_pointer = node.next.clone();
+        }
+        list
+    }
+}
+
+impl<T> Drop for List<T> {
+    /// Unlink the nodes one by one, as dropping the chain recursively can overflow the stack on a
+    /// long List.
+    fn drop(&mut self) {
+        self.tail = None;
+        let mut node_pointer = self.head.take();
+        while let Some(node) = node_pointer {
+            node_pointer = node.borrow_mut().next.take();
+        }
+    }
+}
+
+#[cfg(test)]
+mod create_tests {
+    use super::*;
+
+    #[test]
+    fn create_empty_list() {
+        let list: List<u32> = List::empty();
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+    }
+
+    #[test]
+    fn create_list_with_value() {
+        let list = List::new(1);
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.front(), Some(1));
+        assert_eq!(list.back(), Some(1));
+    }
+}
+
+#[cfg(test)]
+mod push_front_tests {
+    use super::*;
+
+    #[test]
+    fn push_front_on_empty_list() {
+        let mut list = List::empty();
+        list.push_front(1);
+        assert_eq!(list.front(), Some(1));
+        assert_eq!(list.back(), Some(1));
+        assert_eq!(list.len(), 1);
+    }
+
+    #[test]
+    fn push_front_keeps_back() {
+        let mut list = List::empty();
+        list.push_front(1);
+        list.push_front(2);
+        list.push_front(3);
+        assert_eq!(list.to_list(), vec![3, 2, 1]);
+        assert_eq!(list.back(), Some(1));
+        assert_eq!(list.len(), 3);
+    }
+}
+
+#[cfg(test)]
+mod push_back_tests {
+    use super::*;
+
+    #[test]
+    fn push_back_on_empty_list() {
+        let mut list = List::empty();
+        list.push_back(1);
+        assert_eq!(list.front(), Some(1));
+        assert_eq!(list.back(), Some(1));
+        assert_eq!(list.len(), 1);
+    }
+
+    #[test]
+    fn push_back_keeps_front() {
+        let mut list = List::empty();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        assert_eq!(list.to_list(), vec![1, 2, 3]);
+        assert_eq!(list.front(), Some(1));
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    fn push_on_both_ends() {
+        let mut list = List::new(2);
+        list.push_front(1);
+        list.push_back(3);
+        list.push_front(0);
+        assert_eq!(list.to_list(), vec![0, 1, 2, 3]);
+        assert_eq!(list.len(), 4);
+    }
+}
+
+#[cfg(test)]
+mod pop_front_tests {
+    use super::*;
+
+    #[test]
+    fn pop_front_on_empty_list() {
+        let mut list: List<u32> = List::empty();
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn pop_front_of_single_value_clears_back() {
+        let mut list = List::new(1);
+        assert_eq!(list.pop_front(), Some(1));
+        assert!(list.is_empty());
+        assert_eq!(list.back(), None);
+
+        list.push_back(2);
+        assert_eq!(list.to_list(), vec![2]);
+    }
+
+    #[test]
+    fn pop_front_in_push_order() {
+        let mut list = List::empty();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.back(), Some(3));
+    }
+}
+
+#[cfg(test)]
+mod splice_tests {
+    use super::*;
+
+    #[test]
+    fn splice_empty_list() {
+        let mut list = List::new(1);
+        list.splice(List::empty());
+        assert_eq!(list.to_list(), vec![1]);
+        assert_eq!(list.back(), Some(1));
+        assert_eq!(list.len(), 1);
+    }
+
+    #[test]
+    fn splice_into_empty_list() {
+        let mut list = List::empty();
+        let mut other = List::new(1);
+        other.push_back(2);
+        list.splice(other);
+        assert_eq!(list.to_list(), vec![1, 2]);
+        assert_eq!(list.back(), Some(2));
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn splice_empty_into_empty_list() {
+        let mut list: List<u32> = List::empty();
+        list.splice(List::empty());
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn splice_single_value_lists() {
+        let mut list = List::new(1);
+        list.splice(List::new(2));
+        assert_eq!(list.to_list(), vec![1, 2]);
+        assert_eq!(list.front(), Some(1));
+        assert_eq!(list.back(), Some(2));
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn push_back_after_splice() {
+        let mut list = List::new(1);
+        let mut other = List::new(2);
+        other.push_back(3);
+        list.splice(other);
+        list.push_back(4);
+        assert_eq!(list.to_list(), vec![1, 2, 3, 4]);
+        assert_eq!(list.len(), 4);
+    }
+
+    #[test]
+    fn pop_front_through_spliced_values() {
+        let mut list = List::new(1);
+        list.splice(List::new(2));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.back(), None);
+        assert_eq!(list.len(), 0);
+    }
+}
+
+#[cfg(test)]
+mod drop_tests {
+    use super::*;
+
+    #[test]
+    fn drop_long_list() {
+        let mut list = List::empty();
+        for value in 0..100_000 {
+            list.push_back(value);
+        }
+        assert_eq!(list.len(), 100_000);
+        drop(list);
+    }
+
+    #[test]
+    fn drop_long_spliced_list() {
+        let mut list = List::new(0);
+        let mut other = List::empty();
+        for value in 1..100_000 {
+            other.push_back(value);
+        }
+        list.splice(other);
+        assert_eq!(list.len(), 100_000);
+        drop(list);
+    }
+}
@@ -0,0 +1,46 @@
+//! Node of a List, that can be relinked in place.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Shared link to a [MutableNode], held by the previous node and by the List for its ends.
+pub(super) type Link<T> = Option<Rc<RefCell<MutableNode<T>>>>;
+
+/// Node of a List, holding a value and the link to the next node.
+///
+/// Unlike the nodes of a Stack, the next link can be changed after the node is created, which is
+/// what lets a List append at its tail.
+pub(super) struct MutableNode<T> {
+    pub(super) value: T,
+    pub(super) next: Link<T>,
+}
+
+impl<T> MutableNode<T> {
+    /// Create a shared MutableNode with a value and empty next link.
+    pub(super) fn new(value: T) -> Rc<RefCell<MutableNode<T>>> {
+        Rc::new(RefCell::new(Self { value, next: None }))
+    }
+}
+
+#[cfg(test)]
+mod mutable_node_tests {
+    use super::*;
+
+    #[test]
+    fn initialize_tail_node() {
+        let node = MutableNode::new(1);
+        assert_eq!(node.borrow().value, 1);
+        assert!(node.borrow().next.is_none());
+    }
+
+    #[test]
+    fn relink_next_node() {
+        let node = MutableNode::new(1);
+        let next_node = MutableNode::new(2);
+        node.borrow_mut().next = Some(Rc::clone(&next_node));
+        assert_eq!(Rc::strong_count(&next_node), 2);
+
+        node.borrow_mut().next = None;
+        assert_eq!(Rc::strong_count(&next_node), 1);
+    }
+}
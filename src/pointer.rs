@@ -0,0 +1,115 @@
+//! Reference-counted pointers a Stack can link its nodes with.
+//!
+//! [Rc] is the default and the cheapest. [Arc] uses atomic reference counts, so a Stack of
+//! [Send] and [Sync] values can be shared or moved across threads.
+
+use std::ops::Deref;
+
+/// Family of reference-counted pointers, used as the second type parameter of a
+/// [Stack](crate::Stack).
+///
+/// ```
+/// use solanum::pointer::Arc;
+/// use solanum::Stack;
+///
+/// let mut stack: Stack<u32, Arc> = Stack::default();
+/// stack.push(100);
+///
+/// let handle = std::thread::spawn(move || stack.peek());
+/// assert_eq!(handle.join().unwrap(), Some(100));
+/// ```
+pub trait Pointer {
+    /// Shared pointer to a `U`.
+    type Ptr<U>: Deref<Target = U> + Clone;
+
+    /// Move `value` into a new pointer.
+    fn new<U>(value: U) -> Self::Ptr<U>;
+
+    /// Return a mutable reference to the pointed value, or [None] if it is shared.
+    fn get_mut<U>(pointer: &mut Self::Ptr<U>) -> Option<&mut U>;
+
+    /// Return a mutable reference to the pointed value, cloning it first if it is shared.
+    fn make_mut<U: Clone>(pointer: &mut Self::Ptr<U>) -> &mut U;
+
+    /// Return the pointed value if the pointer is its only owner, or the pointer otherwise.
+    fn try_unwrap<U>(pointer: Self::Ptr<U>) -> Result<U, Self::Ptr<U>>;
+}
+
+/// Single-threaded pointer, backed by [std::rc::Rc].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rc;
+
+impl Pointer for Rc {
+    type Ptr<U> = std::rc::Rc<U>;
+
+    fn new<U>(value: U) -> Self::Ptr<U> {
+        std::rc::Rc::new(value)
+    }
+
+    fn get_mut<U>(pointer: &mut Self::Ptr<U>) -> Option<&mut U> {
+        std::rc::Rc::get_mut(pointer)
+    }
+
+    fn make_mut<U: Clone>(pointer: &mut Self::Ptr<U>) -> &mut U {
+        std::rc::Rc::make_mut(pointer)
+    }
+
+    fn try_unwrap<U>(pointer: Self::Ptr<U>) -> Result<U, Self::Ptr<U>> {
+        std::rc::Rc::try_unwrap(pointer)
+    }
+}
+
+/// Thread-safe pointer, backed by [std::sync::Arc].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Arc;
+
+impl Pointer for Arc {
+    type Ptr<U> = std::sync::Arc<U>;
+
+    fn new<U>(value: U) -> Self::Ptr<U> {
+        std::sync::Arc::new(value)
+    }
+
+    fn get_mut<U>(pointer: &mut Self::Ptr<U>) -> Option<&mut U> {
+        std::sync::Arc::get_mut(pointer)
+    }
+
+    fn make_mut<U: Clone>(pointer: &mut Self::Ptr<U>) -> &mut U {
+        std::sync::Arc::make_mut(pointer)
+    }
+
+    fn try_unwrap<U>(pointer: Self::Ptr<U>) -> Result<U, Self::Ptr<U>> {
+        std::sync::Arc::try_unwrap(pointer)
+    }
+}
+
+#[cfg(test)]
+mod pointer_tests {
+    use super::*;
+
+    fn check_pointer<P: Pointer>() {
+        let mut pointer = P::new(1);
+        *P::get_mut(&mut pointer).unwrap() += 1;
+        assert_eq!(*pointer, 2);
+
+        let mut shared_pointer = pointer.clone();
+        assert!(P::get_mut(&mut shared_pointer).is_none());
+        *P::make_mut(&mut shared_pointer) += 1;
+        assert_eq!((*pointer, *shared_pointer), (2, 3));
+
+        let cloned_pointer = pointer.clone();
+        let pointer = P::try_unwrap(pointer).err().unwrap();
+        drop(cloned_pointer);
+        assert_eq!(P::try_unwrap(pointer).ok(), Some(2));
+    }
+
+    #[test]
+    fn rc_pointer() {
+        check_pointer::<Rc>();
+    }
+
+    #[test]
+    fn arc_pointer() {
+        check_pointer::<Arc>();
+    }
+}
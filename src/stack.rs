@@ -1,28 +1,75 @@
 //! Implementation of mutable Stack with `push()` and `pop()`.
 
+use std::collections::{HashMap, HashSet};
+use std::convert::Infallible;
+use std::error::Error;
+use std::fmt;
+use std::hash::Hash;
+use std::iter::Peekable;
+use std::ops::Index;
 use std::rc::Rc;
+use std::str::FromStr;
 
-#[derive(Debug, PartialEq)]
-struct Node<T> {
+use crate::pointer::{self, Pointer};
+
+/// Node of a Stack, holding a value and the reference to the next node.
+///
+/// Outside of this crate, a Node is an opaque type, only handed out as a raw pointer by
+/// [Stack::into_raw] and [Stack::head_ptr]. Its fields and accessors are private to the crate.
+pub struct Node<T, P: Pointer = pointer::Rc> {
     value: T,
-    next: Option<Rc<Node<T>>>,
+    next: Option<P::Ptr<Node<T, P>>>,
 }
 
-impl<T> Node<T> {
+impl<T, P: Pointer> Node<T, P> {
     /// Create a Node with a value and empty next reference.
-    fn new(value: T) -> Node<T> {
+    fn new(value: T) -> Node<T, P> {
         Self { value, next: None }
     }
 
     /// Create a Node with a value and next reference.
-    fn new_with_next(value: T, next_node: Rc<Node<T>>) -> Node<T> {
+    fn new_with_next(value: T, next_node: P::Ptr<Node<T, P>>) -> Node<T, P> {
         Self {
             value,
-            next: Some(Rc::clone(&next_node)),
+            next: Some(next_node),
+        }
+    }
+
+    /// Return a reference to the value of the Node.
+    pub(crate) fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Return a reference to the next Node, or [None] if this is the bottom Node.
+    pub(crate) fn next(&self) -> Option<&Node<T, P>> {
+        self.next.as_deref()
+    }
+}
+
+impl<T: Clone, P: Pointer> Clone for Node<T, P> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            next: self.next.clone(),
         }
     }
 }
 
+impl<T: fmt::Debug, P: Pointer> fmt::Debug for Node<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Node")
+            .field("value", &self.value)
+            .field("next", &self.next())
+            .finish()
+    }
+}
+
+impl<T: PartialEq, P: Pointer> PartialEq for Node<T, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && self.next() == other.next()
+    }
+}
+
 /// Implementation of a Stack
 ///
 ///
@@ -37,12 +84,28 @@ impl<T> Node<T> {
 /// stack.pop();
 /// stack.push(300);
 ///
-/// assert_eq!(stack.size(), 2);
+/// assert_eq!(stack.len(), 2);
 /// assert_eq!(stack.peek(), Some(300));
 /// assert_eq!(stack.to_list(), vec![300, 100]);
 /// ```
-pub struct Stack<T> {
-    head: Option<Rc<Node<T>>>,
+///
+/// Nodes are linked with the [Pointer] `P`, which defaults to [Rc](pointer::Rc). An
+/// [Arc](pointer::Arc)-backed Stack is created with [Stack::default], [Stack::from_bytes] or
+/// [str::parse], and is [Send] and [Sync] when `T` is:
+///
+/// ```
+/// use solanum::pointer::Arc;
+/// use solanum::Stack;
+///
+/// let mut stack: Stack<u32, Arc> = Stack::default();
+/// stack.push(100);
+/// let cloned_stack = stack.clone();
+///
+/// let handle = std::thread::spawn(move || cloned_stack.to_list());
+/// assert_eq!(handle.join().unwrap(), stack.to_list());
+/// ```
+pub struct Stack<T, P: Pointer = pointer::Rc> {
+    head: Option<P::Ptr<Node<T, P>>>,
 }
 
 impl<T: Clone> Stack<T> {
@@ -52,7 +115,7 @@ impl<T: Clone> Stack<T> {
     /// # use solanum::Stack;
     /// let stack: Stack<u32> = Stack::empty();
     ///
-    /// assert_eq!(stack.size(), 0);
+    /// assert_eq!(stack.len(), 0);
     /// ```
     pub fn empty() -> Stack<T> {
         Self { head: None }
@@ -64,37 +127,190 @@ impl<T: Clone> Stack<T> {
     /// # use solanum::Stack;
     /// let stack = Stack::new(100);
     ///
-    /// assert_eq!(stack.size(), 1);
+    /// assert_eq!(stack.len(), 1);
     /// ```
     pub fn new(value: T) -> Stack<T> {
         let node = Rc::new(Node::new(value));
         Self { head: Some(node) }
     }
 
-    /// Return the Stack size.
+    /// Create a Stack of `n` values generated by calling `f` with each index.
+    ///
+    /// Values are pushed in index order, so `f(0)` is the bottom and `f(n - 1)` is the head.
     ///
     /// ```
     /// # use solanum::Stack;
-    /// let empty_stack: Stack<u32> = Stack::empty();
-    /// assert_eq!(empty_stack.size(), 0);
+    /// let stack = Stack::from_fn(3, |index| index * 10);
     ///
-    /// let stack = Stack::new(100);
-    /// assert_eq!(stack.size(), 1);
+    /// assert_eq!(stack.to_list(), vec![20, 10, 0]);
     /// ```
-    pub fn size(&self) -> u32 {
-        if self.is_empty() {
-            0
+    pub fn from_fn<F>(n: usize, f: F) -> Stack<T>
+    where
+        F: FnMut(usize) -> T,
+    {
+        let mut stack = Stack::empty();
+        stack.push_many((0..n).map(f));
+        stack
+    }
+
+    /// Create a Stack from a [Vec] ordered from the head, so index `0` becomes the head.
+    ///
+    /// This is the inverse of [Stack::to_list]. Use [Stack::push_many] to place the last value of
+    /// the [Vec] on the head instead.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let stack = Stack::from_reversed_vec(vec![3, 2, 1]);
+    ///
+    /// assert_eq!(stack.peek(), Some(3));
+    /// assert_eq!(stack.to_list(), vec![3, 2, 1]);
+    /// ```
+    pub fn from_reversed_vec(v: Vec<T>) -> Stack<T> {
+        Stack::from_list(v)
+    }
+
+    /// Consume the Stack and return a raw pointer to its head node, or a null pointer if the Stack
+    /// is empty.
+    ///
+    /// The nodes are not dropped: ownership moves to the pointer, which can be handed across an
+    /// FFI boundary as an opaque handle. It must be turned back into a Stack with
+    /// [Stack::from_raw] exactly once for the nodes to be freed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the head node is shared with another Stack, as the pointer has to be its only
+    /// owner.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push(100);
+    /// stack.push(200);
+    ///
+    /// let pointer = stack.into_raw();
+    /// let stack = unsafe { Stack::from_raw(pointer) };
+    /// assert_eq!(stack.to_list(), vec![200, 100]);
+    /// ```
+    pub fn into_raw(self) -> *mut Node<T> {
+        match self.head {
+            None => std::ptr::null_mut(),
+            Some(head_node) => {
+                assert_eq!(
+                    Rc::strong_count(&head_node),
+                    1,
+                    "head node is shared with another stack"
+                );
+                Rc::into_raw(head_node) as *mut Node<T>
+            }
+        }
+    }
+
+    /// Rebuild a Stack from a pointer returned by [Stack::into_raw].
+    ///
+    /// # Safety
+    ///
+    /// `pointer` must be null, or come from [Stack::into_raw] on a `Stack<T>` of the same `T`.
+    /// Each pointer must be rebuilt at most once: rebuilding it twice frees the same nodes twice.
+    /// The nodes must not be read or written through the pointer after this call.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let pointer = Stack::new(100).into_raw();
+    /// let stack = unsafe { Stack::from_raw(pointer) };
+    /// assert_eq!(stack.peek(), Some(100));
+    ///
+    /// let empty_stack: Stack<u32> = unsafe { Stack::from_raw(std::ptr::null_mut()) };
+    /// assert!(empty_stack.is_empty());
+    /// ```
+    pub unsafe fn from_raw(pointer: *mut Node<T>) -> Stack<T> {
+        if pointer.is_null() {
+            Stack::empty()
         } else {
-            let mut size = 0;
-            let mut node_pointer = &self.head;
-            while let Some(node) = node_pointer {
-                size += 1;
-                node_pointer = &node.next;
+            // SAFETY: the caller guarantees the pointer comes from `Rc::into_raw` in `into_raw`
+            // and is only rebuilt once.
+            let head_node = unsafe { Rc::from_raw(pointer as *const Node<T>) };
+            Stack {
+                head: Some(head_node),
             }
-            size
         }
     }
 
+    /// Return a raw pointer to the head node, or [None] if the Stack is empty.
+    ///
+    /// The node is opaque outside of this crate, so the pointer is mostly useful as an identity,
+    /// for example to check whether two Stacks share their head node. The Stack keeps ownership of
+    /// its nodes, so the caller must uphold the following when dereferencing the pointer:
+    ///
+    /// - The pointer is only valid while the head node is alive. Any method taking `&mut self`
+    ///   (such as [Stack::push] or [Stack::pop]) or dropping the Stack may free or replace it, so
+    ///   the pointer must not be used afterwards.
+    /// - Nodes may be shared with other Stacks (see [Stack::clone]), so they must never be written
+    ///   through the pointer; casting it to `*mut` and mutating is undefined behavior.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push(100);
+    /// let mut cloned_stack = stack.clone();
+    /// assert_eq!(stack.head_ptr(), cloned_stack.head_ptr());
+    ///
+    /// cloned_stack.push(200);
+    /// assert_ne!(stack.head_ptr(), cloned_stack.head_ptr());
+    /// ```
+    pub fn head_ptr(&self) -> Option<*const Node<T>> {
+        self.head.as_ref().map(Rc::as_ptr)
+    }
+
+    /// Return the number of strong references to the head node, or `0` if the Stack is empty.
+    ///
+    /// This is mostly useful to inspect how many Stacks share the same nodes.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let stack = Stack::new(100);
+    /// assert_eq!(stack.head_strong_count(), 1);
+    ///
+    /// let cloned_stack = stack.clone();
+    /// assert_eq!(stack.head_strong_count(), 2);
+    /// assert_eq!(cloned_stack.head_strong_count(), 2);
+    /// ```
+    pub fn head_strong_count(&self) -> usize {
+        match &self.head {
+            None => 0,
+            Some(node) => Rc::strong_count(node),
+        }
+    }
+}
+
+impl<T: Clone, P: Pointer> Stack<T, P> {
+    /// Return the number of values in the Stack.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let empty_stack: Stack<u32> = Stack::empty();
+    /// assert_eq!(empty_stack.len(), 0);
+    ///
+    /// let stack = Stack::new(100);
+    /// assert_eq!(stack.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        let mut len = 0;
+        let mut node_pointer = &self.head;
+        while let Some(node) = node_pointer {
+            len += 1;
+            node_pointer = &node.next;
+        }
+        len
+    }
+
+    /// Return the Stack size.
+    ///
+    /// Prefer [Stack::len], which returns [usize] like the standard collections.
+    #[deprecated(note = "use `Stack::len` instead")]
+    pub fn size(&self) -> u32 {
+        self.len() as u32
+    }
+
     /// Check if Stack is empty.
     ///
     /// ```
@@ -107,17 +323,33 @@ impl<T: Clone> Stack<T> {
         self.head.is_none()
     }
 
+    /// Check if Stack is full, which is never the case as a Stack has no capacity limit.
+    ///
+    /// This mirrors [FixedStack::is_full](crate::FixedStack::is_full), so code can check either
+    /// kind of stack before pushing.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(0..1000);
+    ///
+    /// assert!(!stack.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        false
+    }
+
     /// Return the head value without removing it from the Stack.
     ///
     /// ```
     /// # use solanum::Stack;
     /// let empty_stack: Stack<u32> = Stack::empty();
     /// assert_eq!(empty_stack.peek(), None);
-    /// assert_eq!(empty_stack.size(), 0);
+    /// assert_eq!(empty_stack.len(), 0);
     ///
     /// let stack = Stack::new(1000);
     /// assert_eq!(stack.peek(), Some(1000));
-    /// assert_eq!(stack.size(), 1);
+    /// assert_eq!(stack.len(), 1);
     /// ```
     pub fn peek(&self) -> Option<T> {
         if self.is_empty() {
@@ -134,22 +366,22 @@ impl<T: Clone> Stack<T> {
     /// # use solanum::Stack;
     /// let mut stack: Stack<u32> = Stack::empty();
     /// assert_eq!(stack.peek(), None);
-    /// assert_eq!(stack.size(), 0);
+    /// assert_eq!(stack.len(), 0);
     ///
     /// stack.push(100);
     /// assert_eq!(stack.peek(), Some(100));
-    /// assert_eq!(stack.size(), 1);
+    /// assert_eq!(stack.len(), 1);
     ///
     /// stack.push(200);
     /// assert_eq!(stack.peek(), Some(200));
-    /// assert_eq!(stack.size(), 2);
+    /// assert_eq!(stack.len(), 2);
     /// ```
     pub fn push(&mut self, value: T) {
         if self.is_empty() {
-            self.head = Some(Rc::new(Node::new(value)));
+            self.head = Some(P::new(Node::new(value)));
         } else {
             let head_node = self.head.take().unwrap();
-            self.head = Some(Rc::new(Node::new_with_next(value, head_node)));
+            self.head = Some(P::new(Node::new_with_next(value, head_node)));
         }
     }
 
@@ -171,7 +403,7 @@ impl<T: Clone> Stack<T> {
             let head_node = self.head.take().unwrap();
             match &head_node.next {
                 None => self.head = None,
-                Some(node) => self.head = Some(Rc::clone(node)),
+                Some(node) => self.head = Some(node.clone()),
             }
             Some(head_node.value.clone())
         }
@@ -198,363 +430,5300 @@ impl<T: Clone> Stack<T> {
         }
         list
     }
-}
-
-#[cfg(test)]
-mod node_tests {
-    use super::*;
 
-    #[test]
-    fn initialize_tail_node() {
-        let node = Node::new(1);
-        assert_eq!(node.value, 1);
-        assert!(node.next.is_none());
+    /// Traverse the Stack and yield a reference to each value, starting from the head.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push(1000);
+    /// stack.push(2000);
+    ///
+    /// let values: Vec<_> = stack.iter().collect();
+    /// assert_eq!(values, vec![&2000, &1000]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T, P> {
+        Iter {
+            node: self.head.as_deref(),
+        }
     }
 
-    #[test]
-    fn initialize_node_with_next_reference() {
-        let tail_node = Rc::new(Node::new(1));
-        let node = Node::new_with_next(2, Rc::clone(&tail_node));
-        assert_eq!(node.value, 2);
-        assert!(node.next.is_some());
-        assert_eq!(node.next.as_ref().unwrap().value, 1);
-        assert_eq!(node.next.unwrap(), tail_node);
+    /// Traverse the Stack from the head with an iterator that can [Peekable::peek] the next value
+    /// without advancing.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![1, 2]);
+    ///
+    /// let mut values = stack.iter_peekable();
+    /// assert_eq!(values.peek(), Some(&&2));
+    /// assert_eq!(values.next(), Some(&2));
+    /// assert_eq!(values.next(), Some(&1));
+    /// assert_eq!(values.peek(), None);
+    /// ```
+    pub fn iter_peekable(&self) -> Peekable<Iter<'_, T, P>> {
+        self.iter().peekable()
     }
 
-    #[test]
-    fn primitive_node() {
-        let integer_node = Node::new(1);
-        assert_eq!(integer_node.value, 1);
-
-        let float_node = Node::new(0.1);
-        assert_eq!(float_node.value, 0.1);
-
-        let boolean_node = Node::new(true);
-        assert!(boolean_node.value);
-
-        let str_node = Node::new("hello");
-        assert_eq!(str_node.value, "hello");
+    /// Traverse the Stack and yield a reference to each value, starting from the bottom.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push(1000);
+    /// stack.push(2000);
+    /// stack.push(3000);
+    ///
+    /// let values: Vec<_> = stack.iter_rev().collect();
+    /// assert_eq!(values, vec![&1000, &2000, &3000]);
+    /// ```
+    pub fn iter_rev(&self) -> impl Iterator<Item = &T> {
+        let mut values: Vec<&T> = Vec::new();
+        let mut node_pointer = &self.head;
+        while let Some(node) = node_pointer {
+            values.push(&node.value);
+            node_pointer = &node.next;
+        }
+        values.into_iter().rev()
     }
 
-    #[test]
-    fn complex_node() {
-        #[allow(dead_code)]
-        struct Point {
-            x: u32,
-            y: u32,
+    /// Replace the head value of the Stack and return the previous one.
+    ///
+    /// Returns [Some] with the replaced value, or [None] if stack is empty, in which case the new
+    /// value is not inserted.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::new(100);
+    /// assert_eq!(stack.replace_top(200), Some(100));
+    /// assert_eq!(stack.peek(), Some(200));
+    /// assert_eq!(stack.len(), 1);
+    ///
+    /// let mut empty_stack: Stack<u32> = Stack::empty();
+    /// assert_eq!(empty_stack.replace_top(100), None);
+    /// assert!(empty_stack.is_empty());
+    /// ```
+    pub fn replace_top(&mut self, value: T) -> Option<T> {
+        let head_node = self.head.as_mut()?;
+        match P::get_mut(head_node) {
+            Some(node) => Some(std::mem::replace(&mut node.value, value)),
+            None => {
+                // the head is shared with another owner, so relink a fresh head instead
+                let old_value = head_node.value.clone();
+                let next = head_node.next.clone();
+                *head_node = P::new(Node { value, next });
+                Some(old_value)
+            }
         }
-        let _point_node = Node::new(Point { x: 1, y: 2 });
     }
 
-    #[test]
-    fn reference_count_in_node_next() {
-        let node_1 = Rc::new(Node::new(1));
-        let node_2 = Rc::new(Node::new_with_next(2, Rc::clone(&node_1)));
-
-        assert_eq!(Rc::strong_count(&node_1), 2); // node_1 & being referenced by node_2.next
-        assert_eq!(Rc::strong_count(&node_2), 1); // node_2
+    /// Create a new Stack with the values of this Stack placed on top of the values of `other`.
+    ///
+    /// The resulting [Stack::to_list] is `self.to_list()` followed by `other.to_list()`: concatenating
+    /// `[b, a]` with `[d, c]` yields `[b, a, d, c]`. Both Stacks are left unchanged, and the nodes of
+    /// `other` are shared with the new Stack instead of being copied.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut top = Stack::empty();
+    /// top.push(1);
+    /// top.push(2);
+    ///
+    /// let mut bottom = Stack::empty();
+    /// bottom.push(3);
+    /// bottom.push(4);
+    ///
+    /// let stack = top.concat(&bottom);
+    /// assert_eq!(stack.to_list(), vec![2, 1, 4, 3]);
+    /// assert_eq!(top.to_list(), vec![2, 1]);
+    /// assert_eq!(bottom.to_list(), vec![4, 3]);
+    /// ```
+    pub fn concat(&self, other: &Stack<T, P>) -> Stack<T, P> {
+        let mut stack = Stack {
+            head: other.head.clone(),
+        };
+        for value in self.to_list().into_iter().rev() {
+            stack.push(value);
+        }
+        stack
     }
 
-    #[test]
-    fn reference_count_is_reduced_after_unlink() {
-        let node_1 = Rc::new(Node::new(1));
-        assert_eq!(Rc::strong_count(&node_1), 1); // node_1 itself
-
-        {
-            let _node_2 = Rc::new(Node::new_with_next(2, Rc::clone(&node_1)));
-            assert_eq!(Rc::strong_count(&node_1), 2); // node_1 & being referenced by node_2.next
+    /// Remove the top `n` values of the Stack and return them as a new Stack, preserving their order.
+    ///
+    /// When `n` is greater than or equal to the Stack size, the whole Stack is moved out.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push(1);
+    /// stack.push(2);
+    /// stack.push(3);
+    ///
+    /// let top = stack.take(2);
+    /// assert_eq!(top.to_list(), vec![3, 2]);
+    /// assert_eq!(stack.to_list(), vec![1]);
+    /// ```
+    pub fn take(&mut self, n: usize) -> Stack<T, P> {
+        let mut list: Vec<T> = Vec::new();
+        while list.len() < n {
+            match self.pop() {
+                Some(value) => list.push(value),
+                None => break,
+            }
         }
-        // here, node_2 is dropped
-
-        assert_eq!(Rc::strong_count(&node_1), 1); // node_1 only, as node_2 has been dropped
+        Stack::from_list(list)
     }
-}
-
-#[cfg(test)]
-mod create_tests {
-    use super::*;
 
-    #[test]
-    fn create_stack_with_empty() {
-        let stack: Stack<u32> = Stack::empty();
-        assert!(stack.head.is_none());
+    /// Create a Stack from a [Vec] ordered from the head, the inverse of [Stack::to_list].
+    pub(crate) fn from_list(list: Vec<T>) -> Stack<T, P> {
+        let mut stack = Stack::default();
+        for value in list.into_iter().rev() {
+            stack.push(value);
+        }
+        stack
     }
 
-    #[test]
-    fn create_stack_with_new() {
-        let stack = Stack::new(1);
-        assert!(stack.head.is_some());
+    /// Group consecutive values sharing the same key, starting from the head.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push(3);
+    /// stack.push(3);
+    /// stack.push(2);
+    /// stack.push(1);
+    /// stack.push(1);
+    ///
+    /// assert_eq!(stack.group_by(|value| *value), vec![vec![1, 1], vec![2], vec![3, 3]]);
+    /// ```
+    pub fn group_by<F, K>(&self, key: F) -> Vec<Vec<T>>
+    where
+        F: Fn(&T) -> K,
+        K: PartialEq,
+    {
+        let mut groups: Vec<Vec<T>> = Vec::new();
+        let mut last_key: Option<K> = None;
+        let mut node_pointer = &self.head;
+        while let Some(node) = node_pointer {
+            let current_key = key(&node.value);
+            match groups.last_mut() {
+                Some(group) if last_key.as_ref() == Some(&current_key) => {
+                    group.push(node.value.clone())
+                }
+                _ => groups.push(vec![node.value.clone()]),
+            }
+            last_key = Some(current_key);
+            node_pointer = &node.next;
+        }
+        groups
+    }
 
-        let first_node = stack.head.as_ref().unwrap();
-        assert_eq!(first_node.value, 1);
-        assert!(first_node.next.is_none());
+    /// Return the index, counted from the head, of the last value equal to `value`.
+    ///
+    /// This is the match nearest to the bottom of the Stack, or [None] if no value matches.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push(1);
+    /// stack.push(2);
+    /// stack.push(1);
+    ///
+    /// assert_eq!(stack.rposition(&1), Some(2));
+    /// assert_eq!(stack.rposition(&3), None);
+    /// ```
+    pub fn rposition(&self, value: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        let mut position = None;
+        let mut index = 0;
+        let mut node_pointer = &self.head;
+        while let Some(node) = node_pointer {
+            if node.value == *value {
+                position = Some(index);
+            }
+            index += 1;
+            node_pointer = &node.next;
+        }
+        position
     }
 
-    #[test]
-    fn primitive_stack() {
-        let integer_stack = Stack::new(1);
-        assert_eq!(integer_stack.peek(), Some(1));
+    /// Push every value in order, so the last value ends up on the head of the Stack.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::new(100);
+    /// stack.push_many(vec![200, 300]);
+    ///
+    /// assert_eq!(stack.len(), 3);
+    /// assert_eq!(stack.to_list(), vec![300, 200, 100]);
+    /// ```
+    pub fn push_many(&mut self, values: impl IntoIterator<Item = T>) {
+        for value in values {
+            self.push(value);
+        }
+    }
+
+    /// Consume the Stack and return all values as [Vec], starting from the head.
+    ///
+    /// Unlike [Stack::to_list], values are moved out of nodes that are not shared with another
+    /// Stack, so they are only cloned when a node is shared.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push(1000);
+    /// stack.push(2000);
+    /// stack.push(3000);
+    ///
+    /// assert_eq!(stack.into_list(), vec![3000, 2000, 1000]);
+    /// ```
+    pub fn into_list(self) -> Vec<T> {
+        let mut list: Vec<T> = Vec::new();
+        self.collect_into(&mut list);
+        list
+    }
+
+    /// Split the values into two new Stacks: the ones matching `predicate` and the ones that do not.
+    ///
+    /// Both Stacks preserve the relative order of the original Stack, which is left unchanged.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(1..=5);
+    ///
+    /// let (even, odd) = stack.partition(|value| value % 2 == 0);
+    /// assert_eq!(even.to_list(), vec![4, 2]);
+    /// assert_eq!(odd.to_list(), vec![5, 3, 1]);
+    /// ```
+    pub fn partition<F>(&self, predicate: F) -> (Stack<T, P>, Stack<T, P>)
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut matching: Vec<T> = Vec::new();
+        let mut non_matching: Vec<T> = Vec::new();
+        let mut node_pointer = &self.head;
+        while let Some(node) = node_pointer {
+            if predicate(&node.value) {
+                matching.push(node.value.clone());
+            } else {
+                non_matching.push(node.value.clone());
+            }
+            node_pointer = &node.next;
+        }
+        (Stack::from_list(matching), Stack::from_list(non_matching))
+    }
+
+    /// Pop the head value of the Stack, calling `on_pop` with the value before returning it.
+    ///
+    /// `on_pop` is not called if the Stack is already empty.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::new(100);
+    /// let mut popped = Vec::new();
+    ///
+    /// assert_eq!(stack.pop_with(|value| popped.push(*value)), Some(100));
+    /// assert_eq!(stack.pop_with(|value| popped.push(*value)), None);
+    /// assert_eq!(popped, vec![100]);
+    /// ```
+    pub fn pop_with<F>(&mut self, on_pop: F) -> Option<T>
+    where
+        F: FnOnce(&T),
+    {
+        let value = self.pop()?;
+        on_pop(&value);
+        Some(value)
+    }
+
+    /// Remove the value at `index`, counted from the head, and move the head value into its place.
+    ///
+    /// This does not preserve the order of the Stack: mirroring [Vec::swap_remove], the head value
+    /// fills the emptied slot instead of every value above it shifting down. Removing index `0` is
+    /// the same as [Stack::pop]. Returns [None] if `index` is out of bounds.
+    ///
+    /// This takes O(`index`) time, as the nodes are walked from the head down to `index`. The
+    /// value is swapped in place: only nodes along the way that are shared with another Stack
+    /// (see [Stack::clone]) are copied, and no other node is rebuilt.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![1, 2, 3, 4]);
+    /// assert_eq!(stack.to_list(), vec![4, 3, 2, 1]);
+    ///
+    /// assert_eq!(stack.swap_remove(2), Some(2));
+    /// assert_eq!(stack.to_list(), vec![3, 4, 1]);
+    /// ```
+    pub fn swap_remove(&mut self, index: usize) -> Option<T> {
+        self.iter().nth(index)?;
+        let head_value = self.pop()?;
+        if index == 0 {
+            return Some(head_value);
+        }
+        let mut link = &mut self.head;
+        for _ in 1..index {
+            link = &mut P::make_mut(link.as_mut().unwrap()).next;
+        }
+        let node = P::make_mut(link.as_mut().unwrap());
+        Some(std::mem::replace(&mut node.value, head_value))
+    }
+
+    /// Rotate the Stack in place so the value at index `n`, counted from the head, becomes the head.
+    ///
+    /// Values moved off the head are placed at the bottom, in order. `n` is taken modulo the Stack
+    /// size, and rotating an empty or single value Stack does nothing.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![1, 2, 3, 4]);
+    /// assert_eq!(stack.to_list(), vec![4, 3, 2, 1]);
+    ///
+    /// stack.rotate_left(1);
+    /// assert_eq!(stack.to_list(), vec![3, 2, 1, 4]);
+    /// ```
+    pub fn rotate_left(&mut self, n: usize) {
+        let size = self.len();
+        if size < 2 {
+            return;
+        }
+        let mut list = std::mem::take(self).into_list();
+        list.rotate_left(n % size);
+        *self = Stack::from_list(list);
+    }
+
+    /// Rotate the Stack in place so the value at index `n`, counted from the bottom, becomes the
+    /// bottom.
+    ///
+    /// Values moved off the bottom are placed on the head, in order. `n` is taken modulo the Stack
+    /// size, and rotating an empty or single value Stack does nothing.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![1, 2, 3, 4]);
+    /// assert_eq!(stack.to_list(), vec![4, 3, 2, 1]);
+    ///
+    /// stack.rotate_right(1);
+    /// assert_eq!(stack.to_list(), vec![1, 4, 3, 2]);
+    /// ```
+    pub fn rotate_right(&mut self, n: usize) {
+        let size = self.len();
+        if size < 2 {
+            return;
+        }
+        let mut list = std::mem::take(self).into_list();
+        list.rotate_right(n % size);
+        *self = Stack::from_list(list);
+    }
+
+    /// Insert a value into a Stack sorted in ascending order from the head, keeping it sorted.
+    ///
+    /// The value is placed after any values equal to it. If the Stack is not sorted, the value is
+    /// inserted before the first greater value found from the head.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![5, 3, 1]);
+    /// assert_eq!(stack.to_list(), vec![1, 3, 5]);
+    ///
+    /// stack.insert_sorted(4);
+    /// assert_eq!(stack.to_list(), vec![1, 3, 4, 5]);
+    /// ```
+    pub fn insert_sorted(&mut self, value: T)
+    where
+        T: Ord,
+    {
+        let mut smaller: Vec<T> = Vec::new();
+        while self.head.as_ref().is_some_and(|node| node.value <= value) {
+            smaller.extend(self.pop());
+        }
+        self.push(value);
+        self.push_many(smaller.into_iter().rev());
+    }
+
+    /// Merge two Stacks sorted in ascending order from the head into a single sorted Stack.
+    ///
+    /// Both Stacks are consumed. The merge is stable: equal values keep their relative order, with
+    /// the values of this Stack placed before the ones of `other`.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![5, 3, 1]);
+    ///
+    /// let mut other = Stack::empty();
+    /// other.push_many(vec![6, 4, 2]);
+    ///
+    /// assert_eq!(stack.merge_sorted(other).to_list(), vec![1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn merge_sorted(self, other: Stack<T, P>) -> Stack<T, P>
+    where
+        T: Ord,
+    {
+        let mut left = self.into_list().into_iter().peekable();
+        let mut right = other.into_list().into_iter().peekable();
+        let mut list: Vec<T> = Vec::new();
+        while let (Some(left_value), Some(right_value)) = (left.peek(), right.peek()) {
+            if right_value < left_value {
+                list.extend(right.next());
+            } else {
+                list.extend(left.next());
+            }
+        }
+        list.extend(left);
+        list.extend(right);
+        Stack::from_list(list)
+    }
+
+    /// Sort the Stack in place, in ascending order from the head.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![2, 3, 1]);
+    ///
+    /// stack.sort();
+    /// assert_eq!(stack.to_list(), vec![1, 2, 3]);
+    /// ```
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        let mut list = std::mem::take(self).into_list();
+        list.sort();
+        *self = Stack::from_list(list);
+    }
+
+    /// Create a new Stack of running accumulations, starting from the head.
+    ///
+    /// Each value of the new Stack is `f` applied to the previous accumulation (starting with
+    /// `init`) and the value at the same position. `init` itself is not included.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![3, 2, 1]);
+    /// assert_eq!(stack.to_list(), vec![1, 2, 3]);
+    ///
+    /// let sums = stack.scan(0, |sum, value| sum + value);
+    /// assert_eq!(sums.to_list(), vec![1, 3, 6]);
+    /// ```
+    pub fn scan<B, F>(&self, init: B, f: F) -> Stack<B, P>
+    where
+        B: Clone,
+        F: Fn(&B, &T) -> B,
+    {
+        let mut list: Vec<B> = Vec::new();
+        let mut accumulator = init;
+        let mut node_pointer = &self.head;
+        while let Some(node) = node_pointer {
+            accumulator = f(&accumulator, &node.value);
+            list.push(accumulator.clone());
+            node_pointer = &node.next;
+        }
+        Stack::from_list(list)
+    }
+
+    /// Create a new Stack pairing each value with its index, counted from the head.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec!['c', 'b', 'a']);
+    ///
+    /// assert_eq!(stack.enumerate().to_list(), vec![(0, 'a'), (1, 'b'), (2, 'c')]);
+    /// ```
+    pub fn enumerate(&self) -> Stack<(usize, T), P> {
+        Stack::from_list(self.to_list().into_iter().enumerate().collect())
+    }
+
+    /// Create a new Stack with `separator` inserted between each pair of adjacent values.
+    ///
+    /// Empty and single value Stacks are returned without any separator.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![1, 2, 3]);
+    ///
+    /// assert_eq!(stack.intersperse(0).to_list(), vec![3, 0, 2, 0, 1]);
+    /// ```
+    pub fn intersperse(&self, separator: T) -> Stack<T, P> {
+        let mut list: Vec<T> = Vec::new();
+        let mut node_pointer = &self.head;
+        while let Some(node) = node_pointer {
+            if !list.is_empty() {
+                list.push(separator.clone());
+            }
+            list.push(node.value.clone());
+            node_pointer = &node.next;
+        }
+        Stack::from_list(list)
+    }
+
+    /// Create a new Stack with the longest run of values from the head matching `predicate`.
+    ///
+    /// The original Stack is left unchanged.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![1, 5, 2, 3]);
+    /// assert_eq!(stack.to_list(), vec![3, 2, 5, 1]);
+    ///
+    /// assert_eq!(stack.take_while(|value| *value < 4).to_list(), vec![3, 2]);
+    /// ```
+    pub fn take_while<F>(&self, predicate: F) -> Stack<T, P>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut list: Vec<T> = Vec::new();
+        let mut node_pointer = &self.head;
+        while let Some(node) = node_pointer {
+            if !predicate(&node.value) {
+                break;
+            }
+            list.push(node.value.clone());
+            node_pointer = &node.next;
+        }
+        Stack::from_list(list)
+    }
+
+    /// Create a new Stack without the longest run of values from the head matching `predicate`.
+    ///
+    /// The remaining nodes are shared with the original Stack, which is left unchanged.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![1, 5, 2, 3]);
+    /// assert_eq!(stack.to_list(), vec![3, 2, 5, 1]);
+    ///
+    /// assert_eq!(stack.skip_while(|value| *value < 4).to_list(), vec![5, 1]);
+    /// ```
+    pub fn skip_while<F>(&self, predicate: F) -> Stack<T, P>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut node_pointer = &self.head;
+        while let Some(node) = node_pointer {
+            if !predicate(&node.value) {
+                break;
+            }
+            node_pointer = &node.next;
+        }
+        Stack {
+            head: node_pointer.clone(),
+        }
+    }
+
+    /// Create a new Stack with every `step`-th value, starting with the head.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is `0`.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(1..=5);
+    /// assert_eq!(stack.to_list(), vec![5, 4, 3, 2, 1]);
+    ///
+    /// assert_eq!(stack.step_by(2).to_list(), vec![5, 3, 1]);
+    /// ```
+    pub fn step_by(&self, step: usize) -> Stack<T, P> {
+        assert!(step != 0, "step must be greater than zero");
+        let mut list: Vec<T> = Vec::new();
+        let mut index = 0;
+        let mut node_pointer = &self.head;
+        while let Some(node) = node_pointer {
+            if index % step == 0 {
+                list.push(node.value.clone());
+            }
+            index += 1;
+            node_pointer = &node.next;
+        }
+        Stack::from_list(list)
+    }
+
+    /// Pop the head value of the Stack, or return [EmptyStackError] if stack is already empty.
+    ///
+    /// ```
+    /// # use solanum::{EmptyStackError, Stack};
+    /// let mut stack = Stack::new(100);
+    ///
+    /// assert_eq!(stack.checked_pop(), Ok(100));
+    /// assert_eq!(stack.checked_pop(), Err(EmptyStackError));
+    /// ```
+    pub fn checked_pop(&mut self) -> Result<T, EmptyStackError> {
+        self.pop().ok_or(EmptyStackError)
+    }
+
+    /// Return a reference to the head value, or [EmptyStackError] if stack is empty.
+    ///
+    /// ```
+    /// # use solanum::{EmptyStackError, Stack};
+    /// let stack = Stack::new(100);
+    /// assert_eq!(stack.checked_peek(), Ok(&100));
+    ///
+    /// let stack: Stack<u32> = Stack::empty();
+    /// assert_eq!(stack.checked_peek(), Err(EmptyStackError));
+    /// ```
+    pub fn checked_peek(&self) -> Result<&T, EmptyStackError> {
+        self.head
+            .as_ref()
+            .map(|node| &node.value)
+            .ok_or(EmptyStackError)
+    }
+
+    /// Push `count` clones of `value` on the head of the Stack.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::new(1);
+    /// stack.fill(0, 3);
+    ///
+    /// assert_eq!(stack.len(), 4);
+    /// assert_eq!(stack.to_list(), vec![0, 0, 0, 1]);
+    /// ```
+    pub fn fill(&mut self, value: T, count: usize) {
+        for _ in 0..count {
+            self.push(value.clone());
+        }
+    }
+
+    /// Consume the Stack and append all values to `out`, starting from the head.
+    ///
+    /// Existing values of `out` are kept and its capacity is reused. As with [Stack::into_list],
+    /// values are only cloned when a node is shared with another Stack.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push(2000);
+    /// stack.push(3000);
+    ///
+    /// let mut out = vec![1000];
+    /// stack.collect_into(&mut out);
+    /// assert_eq!(out, vec![1000, 3000, 2000]);
+    /// ```
+    pub fn collect_into(self, out: &mut Vec<T>) {
+        let mut node_pointer = self.head;
+        while let Some(node) = node_pointer {
+            match P::try_unwrap(node) {
+                Ok(node) => {
+                    out.push(node.value);
+                    node_pointer = node.next;
+                }
+                Err(node) => {
+                    out.push(node.value.clone());
+                    node_pointer = node.next.clone();
+                }
+            }
+        }
+    }
+
+    /// Rebuild the Stack with freshly allocated nodes, in a single pass.
+    ///
+    /// The order is unchanged. Values are moved out of nodes that are not shared with another
+    /// Stack, while shared nodes are copied, so the compacted Stack no longer shares any node.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![1, 2, 3]);
+    ///
+    /// stack.compact();
+    /// assert_eq!(stack.to_list(), vec![3, 2, 1]);
+    /// ```
+    pub fn compact(&mut self) {
+        let list = std::mem::take(self).into_list();
+        *self = Stack::from_list(list);
+    }
+
+    /// Take a read-only [StackSnapshot] of the current values of the Stack.
+    ///
+    /// The snapshot shares the nodes of the Stack instead of copying them, and later changes to
+    /// the Stack do not affect it.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push(100);
+    /// stack.push(200);
+    ///
+    /// let snapshot = stack.snapshot();
+    /// stack.pop();
+    /// stack.push(300);
+    ///
+    /// assert_eq!(stack.to_list(), vec![300, 100]);
+    /// assert_eq!(snapshot.to_list(), vec![200, 100]);
+    /// ```
+    pub fn snapshot(&self) -> StackSnapshot<T, P> {
+        StackSnapshot {
+            head: self.head.clone(),
+        }
+    }
+
+    /// Fold the values of the Stack from the head, stopping at the first step returning [Err].
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![1, 2, 3]);
+    ///
+    /// let sum: Result<u32, String> = stack.try_fold(0, |sum, value| Ok(sum + value));
+    /// assert_eq!(sum, Ok(6));
+    ///
+    /// let checked = stack.try_fold(0u32, |sum, value| sum.checked_sub(*value).ok_or(*value));
+    /// assert_eq!(checked, Err(3));
+    /// ```
+    pub fn try_fold<B, E, F>(&self, init: B, f: F) -> Result<B, E>
+    where
+        F: Fn(B, &T) -> Result<B, E>,
+    {
+        let mut accumulator = init;
+        let mut node_pointer = &self.head;
+        while let Some(node) = node_pointer {
+            accumulator = f(accumulator, &node.value)?;
+            node_pointer = &node.next;
+        }
+        Ok(accumulator)
+    }
+
+    /// Create a new Stack with the values of this Stack repeated `n` times, from the head.
+    ///
+    /// The bottom copy shares its nodes with this Stack, which is left unchanged.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![1, 2]);
+    ///
+    /// assert_eq!(stack.repeat(2).to_list(), vec![2, 1, 2, 1]);
+    /// ```
+    pub fn repeat(&self, n: usize) -> Stack<T, P> {
+        if n == 0 {
+            return Stack::default();
+        }
+        let list = self.to_list();
+        let mut stack = self.clone();
+        for _ in 1..n {
+            stack.push_many(list.iter().rev().cloned());
+        }
+        stack
+    }
+
+    /// Remove consecutive values that resolve to the same key, keeping the first of each run.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![30, 21, 20, 11, 10]);
+    ///
+    /// stack.dedup_by_key(|value| value / 10);
+    /// assert_eq!(stack.to_list(), vec![10, 20, 30]);
+    /// ```
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: PartialEq,
+        F: FnMut(&T) -> K,
+    {
+        let mut list = std::mem::take(self).into_list();
+        list.dedup_by_key(|value| key(value));
+        *self = Stack::from_list(list);
+    }
+
+    /// Return a reference to the value with the greatest key, or [None] if the Stack is empty.
+    ///
+    /// If several values share the greatest key, the one nearest to the head is returned.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec!["b", "ccc", "aa"]);
+    ///
+    /// assert_eq!(stack.max_by_key(|value| value.len()), Some(&"ccc"));
+    /// ```
+    pub fn max_by_key<K, F>(&self, f: F) -> Option<&T>
+    where
+        K: Ord,
+        F: Fn(&T) -> K,
+    {
+        let mut max: Option<(K, &T)> = None;
+        let mut node_pointer = &self.head;
+        while let Some(node) = node_pointer {
+            let key = f(&node.value);
+            if max.as_ref().is_none_or(|(max_key, _)| key > *max_key) {
+                max = Some((key, &node.value));
+            }
+            node_pointer = &node.next;
+        }
+        max.map(|(_, value)| value)
+    }
+
+    /// Return a reference to the value with the smallest key, or [None] if the Stack is empty.
+    ///
+    /// If several values share the smallest key, the one nearest to the head is returned.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec!["b", "ccc", "aa"]);
+    ///
+    /// assert_eq!(stack.min_by_key(|value| value.len()), Some(&"b"));
+    /// ```
+    pub fn min_by_key<K, F>(&self, f: F) -> Option<&T>
+    where
+        K: Ord,
+        F: Fn(&T) -> K,
+    {
+        let mut min: Option<(K, &T)> = None;
+        let mut node_pointer = &self.head;
+        while let Some(node) = node_pointer {
+            let key = f(&node.value);
+            if min.as_ref().is_none_or(|(min_key, _)| key < *min_key) {
+                min = Some((key, &node.value));
+            }
+            node_pointer = &node.next;
+        }
+        min.map(|(_, value)| value)
+    }
+
+    /// Fold the values of the Stack from the head, using the head value as the initial
+    /// accumulation.
+    ///
+    /// Returns [None] if the Stack is empty.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![1, 2, 3]);
+    ///
+    /// assert_eq!(stack.reduce(|sum, value| sum + value), Some(6));
+    /// ```
+    pub fn reduce<F>(&self, f: F) -> Option<T>
+    where
+        F: Fn(T, &T) -> T,
+    {
+        let head_node = self.head.as_ref()?;
+        let mut accumulator = head_node.value.clone();
+        let mut node_pointer = &head_node.next;
+        while let Some(node) = node_pointer {
+            accumulator = f(accumulator, &node.value);
+            node_pointer = &node.next;
+        }
+        Some(accumulator)
+    }
+
+    /// Pop the bottom value of the Stack.
+    ///
+    /// Returns [Some] if value exists, or [None] if stack is already empty. Nodes along the way
+    /// that are shared with another Stack are copied, so the other Stack is left unchanged.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push(100);
+    /// stack.push(200);
+    ///
+    /// assert_eq!(stack.pop_back(), Some(100));
+    /// assert_eq!(stack.to_list(), vec![200]);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        let mut link = &mut self.head;
+        while link.as_ref()?.next.is_some() {
+            link = &mut P::make_mut(link.as_mut().unwrap()).next;
+        }
+        let last_node = link.take()?;
+        match P::try_unwrap(last_node) {
+            Ok(node) => Some(node.value),
+            Err(node) => Some(node.value.clone()),
+        }
+    }
+
+    /// Swap the values at indices `i` and `j`, counted from the head.
+    ///
+    /// Nodes along the way that are shared with another Stack are copied, so the other Stack is
+    /// left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` or `j` is out of bounds.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![1, 2, 3]);
+    ///
+    /// stack.swap(0, 2);
+    /// assert_eq!(stack.to_list(), vec![1, 2, 3]);
+    /// ```
+    pub fn swap(&mut self, i: usize, j: usize) {
+        let len = self.len();
+        let (low, high) = (i.min(j), i.max(j));
+        assert!(
+            high < len,
+            "index out of bounds: the len is {len} but the index is {high}"
+        );
+        if low == high {
+            return;
+        }
+
+        let mut link = &mut self.head;
+        for _ in 0..low {
+            link = &mut P::make_mut(link.as_mut().unwrap()).next;
+        }
+        let low_node = P::make_mut(link.as_mut().unwrap());
+        let mut link = &mut low_node.next;
+        for _ in low + 1..high {
+            link = &mut P::make_mut(link.as_mut().unwrap()).next;
+        }
+        let high_node = P::make_mut(link.as_mut().unwrap());
+        std::mem::swap(&mut low_node.value, &mut high_node.value);
+    }
+
+    /// Return the head value, or `default` if the Stack is empty.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let empty_stack: Stack<u32> = Stack::empty();
+    /// assert_eq!(empty_stack.top_or(0), 0);
+    ///
+    /// let stack = Stack::new(100);
+    /// assert_eq!(stack.top_or(0), 100);
+    /// ```
+    pub fn top_or(&self, default: T) -> T {
+        self.peek().unwrap_or(default)
+    }
+
+    /// Return the head value, or the result of calling `f` if the Stack is empty.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let empty_stack: Stack<u32> = Stack::empty();
+    /// assert_eq!(empty_stack.top_or_else(|| 0), 0);
+    ///
+    /// let stack = Stack::new(100);
+    /// assert_eq!(stack.top_or_else(|| 0), 100);
+    /// ```
+    pub fn top_or_else<F>(&self, f: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        self.peek().unwrap_or_else(f)
+    }
+
+    /// Traverse the Stack and yield each value with its index, starting from the head at `0`.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push('b');
+    /// stack.push('a');
+    ///
+    /// let pairs: Vec<_> = stack.iter_indices().collect();
+    /// assert_eq!(pairs, vec![(0, &'a'), (1, &'b')]);
+    /// ```
+    pub fn iter_indices(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.iter().enumerate()
+    }
+
+    /// Check if the values of the Stack, starting from the head, are equal to `other`.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![1, 2, 3]);
+    ///
+    /// assert!(stack.eq_slice(&[3, 2, 1]));
+    /// assert!(!stack.eq_slice(&[1, 2, 3]));
+    /// ```
+    pub fn eq_slice(&self, other: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        let mut other_values = other.iter();
+        let mut node_pointer = &self.head;
+        while let Some(node) = node_pointer {
+            match other_values.next() {
+                Some(value) if *value == node.value => node_pointer = &node.next,
+                _ => return false,
+            }
+        }
+        other_values.next().is_none()
+    }
+
+    /// Group the values into chunks of `n` values, starting from the bottom.
+    ///
+    /// Both the chunks and the values inside them are ordered from the bottom, so the first chunk
+    /// holds the oldest values. The last chunk, nearest to the head, holds the remainder and may
+    /// be shorter than `n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is `0`.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(1..=5);
+    /// assert_eq!(stack.to_list(), vec![5, 4, 3, 2, 1]);
+    ///
+    /// assert_eq!(stack.rchunks(2), vec![vec![1, 2], vec![3, 4], vec![5]]);
+    /// ```
+    pub fn rchunks(&self, n: usize) -> Vec<Vec<T>> {
+        assert!(n != 0, "chunk size must be greater than zero");
+        let mut list = self.to_list();
+        list.reverse();
+        list.chunks(n).map(|chunk| chunk.to_vec()).collect()
+    }
+
+    /// Move the first value equal to `value`, counted from the head, onto the head of the Stack.
+    ///
+    /// Returns `true` if a matching value was found, or `false` if the Stack is left unchanged. A
+    /// match that is already the head stays in place without any change to the nodes.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![1, 2, 3]);
+    ///
+    /// assert!(stack.move_to_front(&1));
+    /// assert_eq!(stack.to_list(), vec![1, 3, 2]);
+    /// assert!(!stack.move_to_front(&4));
+    /// ```
+    pub fn move_to_front(&mut self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        let index = match self.iter_indices().find(|(_, other)| *other == value) {
+            None => return false,
+            Some((index, _)) => index,
+        };
+        if index > 0 {
+            let above = self.take(index);
+            let moved = self.pop().unwrap();
+            self.push_many(above.into_list().into_iter().rev());
+            self.push(moved);
+        }
+        true
+    }
+
+    /// Call `f` with a mutable reference to every value, starting from the head.
+    ///
+    /// Values are edited in place when their node is only owned by this Stack, without any
+    /// allocation or clone.
+    ///
+    /// Nodes shared with another Stack (see [Stack::clone]) are copied on write first, so the
+    /// other Stack is left unchanged. Copying a node also shares every node below it, so once a
+    /// shared node is reached, it and all the nodes below it are copied. At worst, this clones
+    /// every value and allocates a new node for each one, taking O(n) time and memory.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![1, 2, 3]);
+    ///
+    /// stack.with_each_mut(|value| *value *= 10);
+    /// assert_eq!(stack.to_list(), vec![30, 20, 10]);
+    /// ```
+    pub fn with_each_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        let mut link = &mut self.head;
+        while let Some(node) = link {
+            let node = P::make_mut(node);
+            f(&mut node.value);
+            link = &mut node.next;
+        }
+    }
+
+    /// Count how many times each distinct value occurs in the Stack.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec!['a', 'b', 'a']);
+    ///
+    /// let frequencies = stack.frequencies();
+    /// assert_eq!(frequencies[&'a'], 2);
+    /// assert_eq!(frequencies[&'b'], 1);
+    /// ```
+    pub fn frequencies(&self) -> HashMap<T, usize>
+    where
+        T: Eq + Hash,
+    {
+        let mut frequencies = HashMap::new();
+        let mut node_pointer = &self.head;
+        while let Some(node) = node_pointer {
+            *frequencies.entry(node.value.clone()).or_insert(0) += 1;
+            node_pointer = &node.next;
+        }
+        frequencies
+    }
+
+    /// Check if the values are in ascending order from the head, allowing equal neighbours.
+    ///
+    /// An empty or single value Stack is always sorted.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![3, 2, 2, 1]);
+    /// assert!(stack.is_sorted());
+    ///
+    /// stack.push(4);
+    /// assert!(!stack.is_sorted());
+    /// ```
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        let mut node_pointer = &self.head;
+        while let Some(node) = node_pointer {
+            if node
+                .next
+                .as_ref()
+                .is_some_and(|next| node.value > next.value)
+            {
+                return false;
+            }
+            node_pointer = &node.next;
+        }
+        true
+    }
+
+    /// Convert the Stack into an array of exactly `N` values, starting from the head.
+    ///
+    /// If the Stack does not hold exactly `N` values it is returned unchanged in [Err].
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![1, 2, 3]);
+    ///
+    /// let stack = stack.try_into_array::<2>().err().unwrap();
+    /// assert_eq!(stack.try_into_array::<3>().ok(), Some([3, 2, 1]));
+    /// ```
+    pub fn try_into_array<const N: usize>(self) -> Result<[T; N], Stack<T, P>> {
+        if self.len() != N {
+            return Err(self);
+        }
+        self.into_list().try_into().map_err(Stack::from_list)
+    }
+
+    /// Remove every value equal to one nearer to the head, keeping only the first occurrence of
+    /// each distinct value.
+    ///
+    /// The remaining values keep their relative order.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![1, 2, 1, 3, 2]);
+    /// assert_eq!(stack.to_list(), vec![2, 3, 1, 2, 1]);
+    ///
+    /// stack.dedup_global();
+    /// assert_eq!(stack.to_list(), vec![2, 3, 1]);
+    /// ```
+    pub fn dedup_global(&mut self)
+    where
+        T: Eq + Hash,
+    {
+        let mut seen = HashSet::new();
+        let mut list = std::mem::take(self).into_list();
+        list.retain(|value| seen.insert(value.clone()));
+        *self = Stack::from_list(list);
+    }
+
+    /// Count the values of the Stack grouped by the key returned from `key`.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![1, 2, 3, 4, 5]);
+    ///
+    /// let histogram = stack.histogram(|value| value % 2 == 0);
+    /// assert_eq!(histogram[&true], 2);
+    /// assert_eq!(histogram[&false], 3);
+    /// ```
+    pub fn histogram<K, F>(&self, key: F) -> HashMap<K, usize>
+    where
+        K: Eq + Hash,
+        F: Fn(&T) -> K,
+    {
+        let mut histogram = HashMap::new();
+        let mut node_pointer = &self.head;
+        while let Some(node) = node_pointer {
+            *histogram.entry(key(&node.value)).or_insert(0) += 1;
+            node_pointer = &node.next;
+        }
+        histogram
+    }
+
+    /// Split the Stack after the first value equal to `value`, starting from the head.
+    ///
+    /// The matching value and every value above it stay in this Stack, and the values below it
+    /// are returned as a new Stack. Returns [None] and leaves the Stack unchanged if no value
+    /// matches.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![1, 2, 3, 4]);
+    ///
+    /// let below = stack.split_at_value(&3).unwrap();
+    /// assert_eq!(stack.to_list(), vec![4, 3]);
+    /// assert_eq!(below.to_list(), vec![2, 1]);
+    /// ```
+    pub fn split_at_value(&mut self, value: &T) -> Option<Stack<T, P>>
+    where
+        T: PartialEq,
+    {
+        let (index, _) = self.iter_indices().find(|(_, other)| *other == value)?;
+        let mut link = &mut self.head;
+        for _ in 0..index {
+            link = &mut P::make_mut(link.as_mut().unwrap()).next;
+        }
+        let node = P::make_mut(link.as_mut().unwrap());
+        Some(Stack {
+            head: node.next.take(),
+        })
+    }
+
+    /// Combine the values of this Stack and `other` pairwise with `f`, starting from the heads.
+    ///
+    /// The resulting Stack keeps the head-first order and stops at the shorter of the two Stacks.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![1, 2, 3]);
+    ///
+    /// let mut other = Stack::empty();
+    /// other.push_many(vec![10, 20]);
+    ///
+    /// let sums = stack.zip_with(&other, |a, b| a + b);
+    /// assert_eq!(sums.to_list(), vec![23, 12]);
+    /// ```
+    pub fn zip_with<U, R, F>(&self, other: &Stack<U, P>, f: F) -> Stack<R, P>
+    where
+        R: Clone,
+        F: Fn(&T, &U) -> R,
+    {
+        let mut list = Vec::new();
+        let mut node_pointer = &self.head;
+        let mut other_node_pointer = &other.head;
+        while let (Some(node), Some(other_node)) = (node_pointer, other_node_pointer) {
+            list.push(f(&node.value, &other_node.value));
+            node_pointer = &node.next;
+            other_node_pointer = &other_node.next;
+        }
+        Stack::from_list(list)
+    }
+
+    /// Fold each chunk of `chunk` values separately, starting from the head, and return one
+    /// result per chunk.
+    ///
+    /// Every chunk starts from a clone of `init`. The last chunk, nearest to the bottom, holds the
+    /// remainder and may be shorter than `chunk`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk` is `0`.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(1..=5);
+    /// assert_eq!(stack.to_list(), vec![5, 4, 3, 2, 1]);
+    ///
+    /// assert_eq!(stack.chunk_fold(2, 0, |sum, value| sum + value), vec![9, 5, 1]);
+    /// ```
+    pub fn chunk_fold<B, F>(&self, chunk: usize, init: B, f: F) -> Vec<B>
+    where
+        B: Clone,
+        F: Fn(B, &T) -> B,
+    {
+        assert!(chunk != 0, "chunk size must be greater than zero");
+        let mut results = Vec::new();
+        let mut accumulator = None;
+        let mut count = 0;
+        let mut node_pointer = &self.head;
+        while let Some(node) = node_pointer {
+            let current = accumulator.take().unwrap_or_else(|| init.clone());
+            accumulator = Some(f(current, &node.value));
+            count += 1;
+            if count == chunk {
+                results.extend(accumulator.take());
+                count = 0;
+            }
+            node_pointer = &node.next;
+        }
+        results.extend(accumulator);
+        results
+    }
+
+    /// Remove every value for which `predicate` returns `true` and return how many were removed.
+    ///
+    /// The remaining values keep their relative order.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(1..=5);
+    ///
+    /// assert_eq!(stack.remove_all(|value| value % 2 == 0), 2);
+    /// assert_eq!(stack.to_list(), vec![5, 3, 1]);
+    /// ```
+    pub fn remove_all<F>(&mut self, mut predicate: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut list = std::mem::take(self).into_list();
+        let size = list.len();
+        list.retain(|value| !predicate(value));
+        let removed = size - list.len();
+        *self = Stack::from_list(list);
+        removed
+    }
+
+    /// Remove values from the bottom of the Stack while `predicate` returns `true`, and return
+    /// them ordered from the bottom.
+    ///
+    /// Draining stops at the first value, counted from the bottom, for which `predicate` returns
+    /// `false`. That value and every value above it stay in the Stack.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![1, 2, 3, 4]);
+    ///
+    /// assert_eq!(stack.drain_bottom_while(|value| *value < 3), vec![1, 2]);
+    /// assert_eq!(stack.to_list(), vec![4, 3]);
+    /// ```
+    pub fn drain_bottom_while<F>(&mut self, mut predicate: F) -> Vec<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut list = std::mem::take(self).into_list();
+        let kept = list.len()
+            - list
+                .iter()
+                .rev()
+                .take_while(|value| predicate(value))
+                .count();
+        let mut drained = list.split_off(kept);
+        drained.reverse();
+        *self = Stack::from_list(list);
+        drained
+    }
+
+    /// Return `count` values taken from the head, wrapping around to the head again after the
+    /// bottom.
+    ///
+    /// An empty Stack always returns an empty [Vec].
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![1, 2, 3]);
+    ///
+    /// assert_eq!(stack.cycle_take(5), vec![3, 2, 1, 3, 2]);
+    /// ```
+    pub fn cycle_take(&self, count: usize) -> Vec<T> {
+        self.iter().cloned().cycle().take(count).collect()
+    }
+
+    /// Consume the Stack and split it into Stacks of `n` values each, starting from the head.
+    ///
+    /// Every Stack keeps the head-first order of its values. The last Stack, holding the values
+    /// nearest to the bottom, may be shorter than `n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is `0`.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(1..=5);
+    ///
+    /// let stacks: Vec<_> = stack.split_every(2).iter().map(Stack::to_list).collect();
+    /// assert_eq!(stacks, vec![vec![5, 4], vec![3, 2], vec![1]]);
+    /// ```
+    pub fn split_every(self, n: usize) -> Vec<Stack<T, P>> {
+        assert!(n != 0, "chunk size must be greater than zero");
+        let mut stacks = Vec::new();
+        let mut values = self.into_list().into_iter();
+        loop {
+            let chunk: Vec<T> = values.by_ref().take(n).collect();
+            if chunk.is_empty() {
+                return stacks;
+            }
+            stacks.push(Stack::from_list(chunk));
+        }
+    }
+
+    /// Consume both Stacks and alternate their values from the heads, starting with this Stack.
+    ///
+    /// Once the shorter Stack runs out, the remaining values of the longer one are placed at the
+    /// bottom in their original order.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec!['b', 'a']);
+    ///
+    /// let mut other = Stack::empty();
+    /// other.push_many(vec!['3', '2', '1']);
+    ///
+    /// assert_eq!(
+    ///     stack.interleave(other).to_list(),
+    ///     vec!['a', '1', 'b', '2', '3']
+    /// );
+    /// ```
+    pub fn interleave(self, other: Stack<T, P>) -> Stack<T, P> {
+        let mut values = self.into_list().into_iter();
+        let mut other_values = other.into_list().into_iter();
+        let mut list = Vec::with_capacity(values.len() + other_values.len());
+        loop {
+            match (values.next(), other_values.next()) {
+                (None, None) => break,
+                (value, other_value) => list.extend(value.into_iter().chain(other_value)),
+            }
+        }
+        Stack::from_list(list)
+    }
+
+    /// Call `f` with a mutable reference to every value, starting from the head, and keep only
+    /// the values for which it returns `true`.
+    ///
+    /// The kept values keep their edits and their relative order.
+    ///
+    /// The Stack is always rebuilt, allocating a new node for every kept value in O(n) time.
+    /// Values whose node is only owned by this Stack are moved without a clone. Values whose node
+    /// is shared with another Stack (see [Stack::clone]) are cloned before `f` is called, so the
+    /// other Stack is left unchanged.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(1..=4);
+    ///
+    /// stack.retain_mut(|value| {
+    ///     *value *= 10;
+    ///     *value > 20
+    /// });
+    /// assert_eq!(stack.to_list(), vec![40, 30]);
+    /// ```
+    pub fn retain_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let mut list = std::mem::take(self).into_list();
+        list.retain_mut(f);
+        *self = Stack::from_list(list);
+    }
+
+    /// Return the number of values and an estimate of the heap bytes taken by the Stack.
+    ///
+    /// Every node is counted as one reference-counted allocation (value, next pointer, strong and
+    /// weak counts), whichever [Pointer] the Stack uses. Nodes shared with another Stack are
+    /// counted by both, and memory owned by the values themselves is not included.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec![1, 2, 3]);
+    ///
+    /// let stats = stack.stats();
+    /// assert_eq!(stats.len, 3);
+    /// assert_eq!(stats.approx_bytes, 3 * Stack::new(1).stats().approx_bytes);
+    /// ```
+    pub fn stats(&self) -> StackStats {
+        let len = self.len();
+        let node_bytes = size_of::<Node<T, P>>() + 2 * size_of::<usize>();
+        StackStats {
+            len,
+            approx_bytes: len * node_bytes,
+        }
+    }
+
+    /// Create a new Stack by calling `f` on every value, starting from the head, or return the
+    /// first [Err] returned by `f`.
+    ///
+    /// The mapped Stack keeps the order of the values, and `f` is not called again after an
+    /// [Err].
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec!["1", "2"]);
+    ///
+    /// let numbers = stack.try_map(|value| value.parse::<u32>()).unwrap();
+    /// assert_eq!(numbers.to_list(), vec![2, 1]);
+    ///
+    /// stack.push("three");
+    /// assert!(stack.try_map(|value| value.parse::<u32>()).is_err());
+    /// ```
+    pub fn try_map<U, E, F>(&self, f: F) -> Result<Stack<U, P>, E>
+    where
+        U: Clone,
+        F: Fn(&T) -> Result<U, E>,
+    {
+        let mut list: Vec<U> = Vec::new();
+        let mut node_pointer = &self.head;
+        while let Some(node) = node_pointer {
+            list.push(f(&node.value)?);
+            node_pointer = &node.next;
+        }
+        Ok(Stack::from_list(list))
+    }
+
+    /// Call `f` on every window of `size` consecutive values, starting from the head, and return
+    /// one result per window.
+    ///
+    /// Windows overlap and each holds its values ordered from the head. A `size` greater than
+    /// the Stack size yields no window.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is `0`.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(1..=4);
+    /// assert_eq!(stack.to_list(), vec![4, 3, 2, 1]);
+    ///
+    /// let sums = stack.window_fold(2, |window| window.iter().sum::<i32>());
+    /// assert_eq!(sums, vec![7, 5, 3]);
+    /// ```
+    pub fn window_fold<B, F>(&self, size: usize, f: F) -> Vec<B>
+    where
+        F: Fn(&[T]) -> B,
+    {
+        assert!(size != 0, "window size must be greater than zero");
+        self.to_list().windows(size).map(f).collect()
+    }
+
+    /// Pop values from the head of the Stack while `predicate` returns `true`, and return how
+    /// many were popped.
+    ///
+    /// The popped values are dropped without being cloned.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push_many(vec!['a', ',', ',']);
+    ///
+    /// assert_eq!(stack.pop_all_matching(|value| *value == ','), 2);
+    /// assert_eq!(stack.to_list(), vec!['a']);
+    /// ```
+    pub fn pop_all_matching<F>(&mut self, predicate: F) -> usize
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut count = 0;
+        while let Some(node) = &self.head {
+            if !predicate(&node.value) {
+                break;
+            }
+            self.head = node.next.clone();
+            count += 1;
+        }
+        count
+    }
+}
+
+impl<P: Pointer> Stack<char, P> {
+    /// Collect the characters into a [String], starting from the head.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let stack: Stack<char> = "abc".parse().unwrap();
+    /// assert_eq!(stack.to_string_top_first(), "cba");
+    /// ```
+    pub fn to_string_top_first(&self) -> String {
+        let mut string = String::new();
+        let mut node_pointer = &self.head;
+        while let Some(node) = node_pointer {
+            string.push(node.value);
+            node_pointer = &node.next;
+        }
+        string
+    }
+}
+
+impl<P: Pointer> FromStr for Stack<char, P> {
+    type Err = Infallible;
+
+    /// Create a Stack by pushing each character of `string` in order, so the last character is
+    /// placed on the head.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let stack: Stack<char> = "abc".parse().unwrap();
+    /// assert_eq!(stack.to_list(), vec!['c', 'b', 'a']);
+    /// ```
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut stack = Stack::default();
+        stack.push_many(string.chars());
+        Ok(stack)
+    }
+}
+
+impl<T, P: Pointer> Default for Stack<T, P> {
+    /// Create an empty Stack.
+    ///
+    /// Unlike [Stack::empty], this works for any [Pointer], such as an [Arc](pointer::Arc)-backed
+    /// Stack that can be shared across threads.
+    ///
+    /// ```
+    /// use solanum::pointer::Arc;
+    /// use solanum::Stack;
+    ///
+    /// let mut stack: Stack<u32, Arc> = Stack::default();
+    /// stack.push(100);
+    /// assert_eq!(stack.to_list(), vec![100]);
+    /// ```
+    fn default() -> Self {
+        Self { head: None }
+    }
+}
+
+impl<T, P: Pointer> Clone for Stack<T, P> {
+    /// Clone the Stack by sharing its nodes with the original, without cloning any value.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::new(100);
+    /// let cloned_stack = stack.clone();
+    /// stack.push(200);
+    ///
+    /// assert_eq!(stack.to_list(), vec![200, 100]);
+    /// assert_eq!(cloned_stack.to_list(), vec![100]);
+    /// ```
+    fn clone(&self) -> Self {
+        Self {
+            head: self.head.clone(),
+        }
+    }
+}
+
+impl<T, P: Pointer> Index<usize> for Stack<T, P> {
+    type Output = T;
+
+    /// Return a reference to the value `index` positions away from the head.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::empty();
+    /// stack.push(100);
+    /// stack.push(200);
+    ///
+    /// assert_eq!(stack[0], 200);
+    /// assert_eq!(stack[1], 100);
+    /// ```
+    fn index(&self, index: usize) -> &T {
+        let mut position = 0;
+        let mut node_pointer = &self.head;
+        while let Some(node) = node_pointer {
+            if position == index {
+                return &node.value;
+            }
+            position += 1;
+            node_pointer = &node.next;
+        }
+        panic!("index out of bounds: the len is {position} but the index is {index}");
+    }
+}
+
+/// Iterator over references to the values of a Stack, starting from the head.
+///
+/// Created by [Stack::iter].
+pub struct Iter<'a, T, P: Pointer = pointer::Rc> {
+    node: Option<&'a Node<T, P>>,
+}
+
+impl<'a, T, P: Pointer> Iterator for Iter<'a, T, P> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.node?;
+        self.node = node.next();
+        Some(node.value())
+    }
+}
+
+impl<T, P: Pointer> Clone for Iter<'_, T, P> {
+    fn clone(&self) -> Self {
+        Self { node: self.node }
+    }
+}
+
+/// Read-only view of a Stack, taken with [Stack::snapshot].
+///
+/// A snapshot pins the nodes the Stack had when it was taken, so it is cheap to create and to
+/// clone, and it is not affected by later changes to the Stack.
+///
+///
+/// Examples:
+///
+/// ```
+/// use solanum::Stack;
+///
+/// let mut stack = Stack::new(100);
+/// let snapshot = stack.snapshot();
+/// stack.push(200);
+///
+/// assert_eq!(snapshot.len(), 1);
+/// assert_eq!(snapshot.to_list(), vec![100]);
+/// ```
+pub struct StackSnapshot<T, P: Pointer = pointer::Rc> {
+    head: Option<P::Ptr<Node<T, P>>>,
+}
+
+impl<T, P: Pointer> StackSnapshot<T, P> {
+    /// Return the snapshot size.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let stack = Stack::new(100);
+    /// assert_eq!(stack.snapshot().len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Check if the snapshot is empty.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let stack: Stack<u32> = Stack::empty();
+    /// assert!(stack.snapshot().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Traverse the snapshot and yield a reference to each value, starting from the head.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::new(100);
+    /// stack.push(200);
+    ///
+    /// let values: Vec<_> = stack.snapshot().iter().copied().collect();
+    /// assert_eq!(values, vec![200, 100]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T, P> {
+        Iter {
+            node: self.head.as_deref(),
+        }
+    }
+}
+
+impl<T: Clone, P: Pointer> StackSnapshot<T, P> {
+    /// Traverse the snapshot and return all values as [Vec], starting from the head.
+    ///
+    /// ```
+    /// # use solanum::Stack;
+    /// let mut stack = Stack::new(100);
+    /// stack.push(200);
+    ///
+    /// assert_eq!(stack.snapshot().to_list(), vec![200, 100]);
+    /// ```
+    pub fn to_list(&self) -> Vec<T> {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T, P: Pointer> Clone for StackSnapshot<T, P> {
+    fn clone(&self) -> Self {
+        Self {
+            head: self.head.clone(),
+        }
+    }
+}
+
+/// Size statistics of a Stack, returned by [Stack::stats].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StackStats {
+    /// Number of values in the Stack.
+    pub len: usize,
+    /// Estimated number of heap bytes taken by the nodes of the Stack.
+    pub approx_bytes: usize,
+}
+
+/// Error returned when a value is requested from an empty Stack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmptyStackError;
+
+impl fmt::Display for EmptyStackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "stack is empty")
+    }
+}
+
+impl Error for EmptyStackError {}
+
+#[cfg(test)]
+mod test_support {
+    use super::*;
+    use std::cell::Cell;
+    use std::cmp::Ordering;
+
+    /// Create a Stack by pushing `values` in order, so the last one ends up on the head.
+    pub(super) fn stack_of(values: impl IntoIterator<Item = u32>) -> Stack<u32> {
+        let mut stack = Stack::empty();
+        stack.push_many(values);
+        stack
+    }
+
+    /// Value recording every clone made of it in a shared counter.
+    #[derive(Debug)]
+    pub(super) struct CloneCounter<'a> {
+        pub(super) value: u32,
+        clones: &'a Cell<u32>,
+    }
+
+    impl<'a> CloneCounter<'a> {
+        pub(super) fn new(value: u32, clones: &'a Cell<u32>) -> CloneCounter<'a> {
+            Self { value, clones }
+        }
+    }
+
+    impl Clone for CloneCounter<'_> {
+        fn clone(&self) -> Self {
+            self.clones.set(self.clones.get() + 1);
+            Self {
+                value: self.value,
+                clones: self.clones,
+            }
+        }
+    }
+
+    /// Value compared and ordered by its key only, with a label telling equal keys apart.
+    #[derive(Clone, Debug)]
+    pub(super) struct Item(pub(super) u32, pub(super) &'static str);
+
+    impl PartialEq for Item {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl Eq for Item {}
+
+    impl PartialOrd for Item {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Item {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod node_tests {
+    use super::*;
+
+    #[test]
+    fn initialize_tail_node() {
+        let node: Node<_> = Node::new(1);
+        assert_eq!(node.value, 1);
+        assert!(node.next.is_none());
+    }
+
+    #[test]
+    fn initialize_node_with_next_reference() {
+        let tail_node: Rc<Node<_>> = Rc::new(Node::new(1));
+        let node: Node<_> = Node::new_with_next(2, Rc::clone(&tail_node));
+        assert_eq!(node.value, 2);
+        assert!(node.next.is_some());
+        assert_eq!(node.next.as_ref().unwrap().value, 1);
+        assert_eq!(node.next.unwrap(), tail_node);
+    }
+
+    #[test]
+    fn node_accessors() {
+        let tail_node: Rc<Node<_>> = Rc::new(Node::new(1));
+        let node: Node<_> = Node::new_with_next(2, Rc::clone(&tail_node));
+        assert_eq!(node.value(), &2);
+        assert_eq!(node.next(), Some(&*tail_node));
+        assert!(tail_node.next().is_none());
+    }
+
+    #[test]
+    fn primitive_node() {
+        let integer_node: Node<_> = Node::new(1);
+        assert_eq!(integer_node.value, 1);
+
+        let float_node: Node<_> = Node::new(0.1);
+        assert_eq!(float_node.value, 0.1);
+
+        let boolean_node: Node<_> = Node::new(true);
+        assert!(boolean_node.value);
+
+        let str_node: Node<_> = Node::new("hello");
+        assert_eq!(str_node.value, "hello");
+    }
+
+    #[test]
+    fn complex_node() {
+        #[allow(dead_code)]
+        struct Point {
+            x: u32,
+            y: u32,
+        }
+        let _point_node: Node<_> = Node::new(Point { x: 1, y: 2 });
+    }
+
+    #[test]
+    fn reference_count_in_node_next() {
+        let node_1: Rc<Node<_>> = Rc::new(Node::new(1));
+        let node_2: Rc<Node<_>> = Rc::new(Node::new_with_next(2, Rc::clone(&node_1)));
+
+        assert_eq!(Rc::strong_count(&node_1), 2); // node_1 & being referenced by node_2.next
+        assert_eq!(Rc::strong_count(&node_2), 1); // node_2
+    }
+
+    #[test]
+    fn reference_count_is_reduced_after_unlink() {
+        let node_1: Rc<Node<_>> = Rc::new(Node::new(1));
+        assert_eq!(Rc::strong_count(&node_1), 1); // node_1 itself
+
+        {
+            let _node_2: Rc<Node<_>> = Rc::new(Node::new_with_next(2, Rc::clone(&node_1)));
+            assert_eq!(Rc::strong_count(&node_1), 2); // node_1 & being referenced by node_2.next
+        }
+        // here, node_2 is dropped
+
+        assert_eq!(Rc::strong_count(&node_1), 1); // node_1 only, as node_2 has been dropped
+    }
+}
+
+#[cfg(test)]
+mod create_tests {
+    use super::*;
+
+    #[test]
+    fn create_stack_with_empty() {
+        let stack: Stack<u32> = Stack::empty();
+        assert!(stack.head.is_none());
+    }
+
+    #[test]
+    fn create_stack_with_new() {
+        let stack = Stack::new(1);
+        assert!(stack.head.is_some());
+
+        let first_node = stack.head.as_ref().unwrap();
+        assert_eq!(first_node.value, 1);
+        assert!(first_node.next.is_none());
+    }
+
+    #[test]
+    fn primitive_stack() {
+        let integer_stack = Stack::new(1);
+        assert_eq!(integer_stack.peek(), Some(1));
+
+        let boolean_stack = Stack::new(false);
+        assert_eq!(boolean_stack.peek(), Some(false));
+
+        let str_stack = Stack::new("asd");
+        assert_eq!(str_stack.peek(), Some("asd"));
+    }
+
+    #[test]
+    fn complex_stack() {
+        #[allow(dead_code)]
+        #[derive(Clone, PartialEq, Debug)]
+        struct Point {
+            x: u32,
+            y: u32,
+        }
+        let point_stack = Stack::new(Point { x: 1, y: 2 });
+        assert_eq!(point_stack.peek(), Some(Point { x: 1, y: 2 }));
+    }
+}
+
+#[cfg(test)]
+mod is_empty_tests {
+    use super::*;
+
+    #[test]
+    fn is_empty_with_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn is_empty_with_filled_stack() {
+        let stack = Stack::new(1);
+        assert!(!stack.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod is_full_tests {
+    use super::*;
+
+    #[test]
+    fn is_full_with_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert!(!stack.is_full());
+    }
+
+    #[test]
+    fn is_full_with_filled_stack() {
+        let mut stack = Stack::empty();
+        stack.push_many(0..10_000);
+        assert!(!stack.is_full());
+    }
+}
+
+#[cfg(test)]
+mod peek_tests {
+    use super::*;
+
+    #[test]
+    fn peek_empty_stack() {
+        let empty_stack: Stack<u32> = Stack::empty();
+        assert_eq!(empty_stack.peek(), None);
+    }
+
+    #[test]
+    fn peek_filled_stack() {
+        let stack = Stack::new(1);
+        assert_eq!(stack.peek(), Some(1));
+        assert_eq!(stack.head.unwrap().value, 1);
+    }
+
+    #[test]
+    fn peek_filled_stack_multiple_times() {
+        let stack = Stack::new(1);
+        assert_eq!(stack.peek(), Some(1));
+        assert_eq!(stack.peek(), Some(1));
+        assert_eq!(stack.peek(), Some(1));
+        assert_eq!(stack.head.unwrap().value, 1);
+    }
+
+    #[test]
+    fn reference_on_peek_is_unchanged() {
+        let node: Rc<Node<_>> = Rc::new(Node {
+            value: 100,
+            next: None,
+        });
+        assert_eq!(Rc::strong_count(&node), 1); // node itself
+
+        {
+            let stack: Stack<_> = Stack {
+                head: Some(Rc::clone(&node)),
+            };
+            assert_eq!(Rc::strong_count(&node), 2); // node itself, and referenced by stack
+
+            let peek_result = stack.peek();
+            assert_eq!(peek_result, Some(100));
+
+            assert_eq!(Rc::strong_count(&node), 2); // peek should not modify strong count
+        }
+        // stack is destroyed here
+
+        assert_eq!(Rc::strong_count(&node), 1); // node itself
+    }
+}
+
+#[cfg(test)]
+#[allow(deprecated)]
+mod size_tests {
+    use super::*;
+
+    #[test]
+    fn size_of_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert_eq!(stack.size(), 0);
+    }
+
+    #[test]
+    fn size_of_single_stack() {
+        let stack = Stack::new(100);
+        assert_eq!(stack.size(), 1);
+    }
+
+    #[test]
+    fn size_of_filled_stack() {
+        let stack: Stack<_> = Stack {
+            head: Some(Rc::new(Node {
+                value: 100,
+                next: Some(Rc::new(Node {
+                    value: 200,
+                    next: Some(Rc::new(Node {
+                        value: 300,
+                        next: None,
+                    })),
+                })),
+            })),
+        };
+        assert_eq!(stack.size(), 3);
+    }
+}
+
+#[cfg(test)]
+mod list_tests {
+    use super::*;
+
+    #[test]
+    fn list_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert_eq!(stack.to_list(), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn list_filled_stack() {
+        let stack: Stack<_> = Stack {
+            head: Some(Rc::new(Node {
+                value: 1,
+                next: Some(Rc::new(Node {
+                    value: 2,
+                    next: Some(Rc::new(Node {
+                        value: 3,
+                        next: None,
+                    })),
+                })),
+            })),
+        };
+        assert_eq!(stack.to_list(), vec![1, 2, 3]);
+    }
+}
+
+#[cfg(test)]
+#[allow(deprecated)]
+mod push_tests {
+    use super::*;
+
+    #[test]
+    fn push_once_to_empty_stack() {
+        let mut stack = Stack::empty();
+        stack.push(1);
+        assert_eq!(stack.size(), 1);
+        assert_eq!(stack.to_list(), vec![1]);
+    }
+
+    #[test]
+    fn push_once_to_filled_stack() {
+        let mut stack = Stack::new(1);
+        stack.push(2);
+        assert_eq!(stack.size(), 2);
+        assert_eq!(stack.to_list(), vec![2, 1]);
+    }
+
+    #[test]
+    fn push_many_times() {
+        let mut stack = Stack::empty();
+        assert_eq!(stack.size(), 0);
+        assert_eq!(stack.to_list(), vec![]);
+
+        stack.push(1);
+        assert_eq!(stack.size(), 1);
+        assert_eq!(stack.to_list(), vec![1]);
+
+        stack.push(2);
+        assert_eq!(stack.size(), 2);
+        assert_eq!(stack.to_list(), vec![2, 1]);
+
+        stack.push(3);
+        assert_eq!(stack.size(), 3);
+        assert_eq!(stack.to_list(), vec![3, 2, 1]);
+    }
+}
+
+#[cfg(test)]
+#[allow(deprecated)]
+mod pop_tests {
+    use super::*;
+
+    #[test]
+    fn pop_on_empty_stack() {
+        let mut stack: Stack<u32> = Stack::empty();
+        let result = stack.pop();
+        assert_eq!(result, None);
+        assert_eq!(stack.size(), 0);
+    }
+
+    #[test]
+    fn pop_on_stack_with_one_element() {
+        let mut stack = Stack::new(1);
+        let result = stack.pop();
+        assert_eq!(result, Some(1));
+        assert_eq!(stack.size(), 0);
+    }
+
+    #[test]
+    fn pop_on_stack_with_several_element() {
+        let mut stack = Stack::empty();
+        stack.push(100);
+        stack.push(200);
+        stack.push(300);
+
+        assert_eq!(stack.size(), 3);
+
+        assert_eq!(stack.pop(), Some(300));
+        assert_eq!(stack.size(), 2);
+
+        assert_eq!(stack.pop(), Some(200));
+        assert_eq!(stack.size(), 1);
+
+        assert_eq!(stack.pop(), Some(100));
+        assert_eq!(stack.size(), 0);
+
+        assert_eq!(stack.pop(), None);
+        assert_eq!(stack.size(), 0);
+
+        assert_eq!(stack.pop(), None);
+        assert_eq!(stack.size(), 0);
+
+        assert_eq!(stack.pop(), None);
+        assert_eq!(stack.size(), 0);
+    }
+
+    #[test]
+    fn reference_on_pop() {
+        let node: Rc<Node<_>> = Rc::new(Node {
+            value: 100,
+            next: None,
+        });
+        assert_eq!(Rc::strong_count(&node), 1); // node itself
+
+        {
+            let mut stack: Stack<_> = Stack {
+                head: Some(Rc::clone(&node)),
+            };
+            assert_eq!(Rc::strong_count(&node), 2); // node itself, and referenced by stack
+
+            let pop_result = stack.pop();
+            assert_eq!(pop_result, Some(100));
+
+            // because stack.head does not reference to node anymore, the count decreased
+            assert_eq!(Rc::strong_count(&node), 1); // node itself
+        }
+        // stack is destroyed here
+
+        assert_eq!(Rc::strong_count(&node), 1); // node itself
+    }
+}
+
+#[cfg(test)]
+mod iter_rev_tests {
+    use super::test_support::CloneCounter;
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn iter_rev_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert_eq!(stack.iter_rev().next(), None);
+    }
+
+    #[test]
+    fn iter_rev_filled_stack() {
+        let mut stack = Stack::empty();
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+
+        let values: Vec<_> = stack.iter_rev().collect();
+        assert_eq!(values, vec![&1, &2, &3]);
+    }
+
+    #[test]
+    fn iter_rev_is_reverse_of_list() {
+        let mut stack = Stack::empty();
+        stack.push(10);
+        stack.push(20);
+        stack.push(30);
+        stack.push(40);
+
+        let mut list = stack.to_list();
+        list.reverse();
+        let reversed: Vec<_> = stack.iter_rev().cloned().collect();
+        assert_eq!(reversed, list);
+    }
+
+    #[test]
+    fn iter_rev_does_not_clone() {
+        let clones = Cell::new(0);
+        let mut stack = Stack::empty();
+        stack.push(CloneCounter::new(1, &clones));
+        stack.push(CloneCounter::new(2, &clones));
+        stack.push(CloneCounter::new(3, &clones));
+
+        assert_eq!(stack.iter_rev().count(), 3);
+        assert_eq!(clones.get(), 0);
+    }
+}
+
+#[cfg(test)]
+mod replace_top_tests {
+    use super::*;
+
+    #[test]
+    fn replace_top_on_empty_stack() {
+        let mut stack: Stack<u32> = Stack::empty();
+        assert_eq!(stack.replace_top(1), None);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn replace_top_on_filled_stack() {
+        let mut stack = Stack::empty();
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+
+        assert_eq!(stack.replace_top(30), Some(3));
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.to_list(), vec![30, 2, 1]);
+    }
+
+    #[test]
+    fn replace_top_on_shared_head() {
+        let node: Rc<Node<_>> = Rc::new(Node {
+            value: 1,
+            next: Some(Rc::new(Node::new(2))),
+        });
+        let other_stack: Stack<_> = Stack {
+            head: Some(Rc::clone(&node)),
+        };
+        let mut stack: Stack<_> = Stack {
+            head: Some(Rc::clone(&node)),
+        };
+        assert_eq!(Rc::strong_count(&node), 3); // node itself, and referenced by both stacks
+
+        assert_eq!(stack.replace_top(10), Some(1));
+        assert_eq!(stack.to_list(), vec![10, 2]);
+        assert_eq!(other_stack.to_list(), vec![1, 2]);
+
+        // stack has been relinked to a fresh head
+        assert_eq!(Rc::strong_count(&node), 2); // node itself, and referenced by other_stack
+    }
+}
+
+#[cfg(test)]
+mod concat_tests {
+    use super::*;
+
+    #[test]
+    fn concat_two_empty_stacks() {
+        let stack: Stack<u32> = Stack::empty();
+        let other: Stack<u32> = Stack::empty();
+        assert!(stack.concat(&other).is_empty());
+    }
+
+    #[test]
+    fn concat_with_empty_other() {
+        let mut stack = Stack::empty();
+        stack.push(1);
+        stack.push(2);
+        let other = Stack::empty();
+
+        assert_eq!(stack.concat(&other).to_list(), vec![2, 1]);
+    }
+
+    #[test]
+    fn concat_onto_empty_self() {
+        let stack = Stack::empty();
+        let mut other = Stack::empty();
+        other.push(1);
+        other.push(2);
+
+        assert_eq!(stack.concat(&other).to_list(), vec![2, 1]);
+    }
+
+    #[test]
+    fn concat_filled_stacks() {
+        let mut stack = Stack::empty();
+        stack.push(1);
+        stack.push(2);
+        let mut other = Stack::empty();
+        other.push(3);
+        other.push(4);
+
+        let result = stack.concat(&other);
+        assert_eq!(result.len(), 4);
+        assert_eq!(result.to_list(), vec![2, 1, 4, 3]);
+        assert_eq!(stack.to_list(), vec![2, 1]);
+        assert_eq!(other.to_list(), vec![4, 3]);
+    }
+
+    #[test]
+    fn concat_shares_nodes_of_other() {
+        let node: Rc<Node<_>> = Rc::new(Node::new(1));
+        let stack = Stack::new(2);
+        let other: Stack<_> = Stack {
+            head: Some(Rc::clone(&node)),
+        };
+        assert_eq!(Rc::strong_count(&node), 2); // node itself, and referenced by other
+
+        {
+            let result = stack.concat(&other);
+            assert_eq!(result.to_list(), vec![2, 1]);
+            assert_eq!(Rc::strong_count(&node), 3); // also referenced by the new stack
+        }
+        // result is destroyed here
+
+        assert_eq!(Rc::strong_count(&node), 2);
+    }
+}
+
+#[cfg(test)]
+mod take_tests {
+    use super::test_support::stack_of;
+    use super::*;
+
+    #[test]
+    fn take_zero() {
+        let mut stack = stack_of(1..=4);
+        let top = stack.take(0);
+        assert!(top.is_empty());
+        assert_eq!(stack.to_list(), vec![4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn take_some() {
+        let mut stack = stack_of(1..=4);
+        let top = stack.take(2);
+        assert_eq!(top.len(), 2);
+        assert_eq!(top.to_list(), vec![4, 3]);
+        assert_eq!(stack.len(), 2);
+        assert_eq!(stack.to_list(), vec![2, 1]);
+    }
+
+    #[test]
+    fn take_exactly_size() {
+        let mut stack = stack_of(1..=4);
+        let top = stack.take(4);
+        assert_eq!(top.to_list(), vec![4, 3, 2, 1]);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn take_more_than_size() {
+        let mut stack = stack_of(1..=4);
+        let top = stack.take(10);
+        assert_eq!(top.to_list(), vec![4, 3, 2, 1]);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn take_from_empty_stack() {
+        let mut stack: Stack<u32> = Stack::empty();
+        assert!(stack.take(3).is_empty());
+        assert!(stack.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod group_by_tests {
+    use super::*;
+
+    #[test]
+    fn group_by_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert_eq!(stack.group_by(|value| *value), Vec::<Vec<u32>>::new());
+    }
+
+    #[test]
+    fn group_by_all_same() {
+        let mut stack = Stack::empty();
+        stack.push(1);
+        stack.push(1);
+        stack.push(1);
+        assert_eq!(stack.group_by(|value| *value), vec![vec![1, 1, 1]]);
+    }
+
+    #[test]
+    fn group_by_all_distinct() {
+        let mut stack = Stack::empty();
+        stack.push(3);
+        stack.push(2);
+        stack.push(1);
+        assert_eq!(
+            stack.group_by(|value| *value),
+            vec![vec![1], vec![2], vec![3]]
+        );
+    }
+
+    #[test]
+    fn group_by_mixed_runs() {
+        let mut stack = Stack::empty();
+        stack.push(3);
+        stack.push(3);
+        stack.push(2);
+        stack.push(1);
+        stack.push(1);
+        assert_eq!(
+            stack.group_by(|value| *value),
+            vec![vec![1, 1], vec![2], vec![3, 3]]
+        );
+    }
+
+    #[test]
+    fn group_by_derived_key() {
+        let mut stack = Stack::empty();
+        stack.push(5);
+        stack.push(4);
+        stack.push(2);
+        stack.push(3);
+        stack.push(1);
+        assert_eq!(
+            stack.group_by(|value| value % 2 == 0),
+            vec![vec![1, 3], vec![2, 4], vec![5]]
+        );
+    }
+}
+
+#[cfg(test)]
+mod head_strong_count_tests {
+    use super::*;
+
+    #[test]
+    fn head_strong_count_of_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert_eq!(stack.head_strong_count(), 0);
+    }
+
+    #[test]
+    fn head_strong_count_after_new() {
+        let stack = Stack::new(1);
+        assert_eq!(stack.head_strong_count(), 1);
+    }
+
+    #[test]
+    fn head_strong_count_after_clone() {
+        let stack = Stack::new(1);
+        {
+            let cloned_stack = stack.clone();
+            assert_eq!(stack.head_strong_count(), 2);
+            assert_eq!(cloned_stack.head_strong_count(), 2);
+        }
+        // cloned_stack is destroyed here
+
+        assert_eq!(stack.head_strong_count(), 1);
+    }
+
+    #[test]
+    fn head_strong_count_after_diverging_push() {
+        let stack = Stack::new(1);
+        let mut cloned_stack = stack.clone();
+        cloned_stack.push(2);
+
+        // the new head is only owned by cloned_stack, while the old head is still shared
+        assert_eq!(cloned_stack.head_strong_count(), 1);
+        assert_eq!(stack.head_strong_count(), 2);
+    }
+}
+
+#[cfg(test)]
+mod rposition_tests {
+    use super::*;
+
+    #[test]
+    fn rposition_on_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert_eq!(stack.rposition(&1), None);
+    }
+
+    #[test]
+    fn rposition_with_multiple_occurrences() {
+        let mut stack = Stack::empty();
+        stack.push(7);
+        stack.push(1);
+        stack.push(7);
+        stack.push(2);
+        stack.push(7);
+        assert_eq!(stack.to_list(), vec![7, 2, 7, 1, 7]);
+        assert_eq!(stack.rposition(&7), Some(4));
+    }
+
+    #[test]
+    fn rposition_with_single_occurrence() {
+        let mut stack = Stack::empty();
+        stack.push(3);
+        stack.push(2);
+        stack.push(1);
+        assert_eq!(stack.rposition(&2), Some(1));
+    }
+
+    #[test]
+    fn rposition_without_match() {
+        let mut stack = Stack::empty();
+        stack.push(3);
+        stack.push(2);
+        stack.push(1);
+        assert_eq!(stack.rposition(&4), None);
+    }
+}
+
+#[cfg(test)]
+mod push_many_tests {
+    use super::*;
+
+    #[test]
+    fn push_many_from_vec() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.to_list(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn push_many_from_range() {
+        let mut stack = Stack::new(0);
+        stack.push_many(1..=4);
+        assert_eq!(stack.len(), 5);
+        assert_eq!(stack.to_list(), vec![4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn push_many_with_no_values() {
+        let mut stack = Stack::new(1);
+        stack.push_many(Vec::new());
+        assert_eq!(stack.len(), 1);
+        assert_eq!(stack.to_list(), vec![1]);
+    }
+}
+
+#[cfg(test)]
+mod index_tests {
+    use super::*;
+
+    #[test]
+    fn index_valid_positions() {
+        let mut stack = Stack::empty();
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+        assert_eq!(stack[0], 3);
+        assert_eq!(stack[1], 2);
+        assert_eq!(stack[2], 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 2 but the index is 2")]
+    fn index_out_of_bounds() {
+        let mut stack = Stack::empty();
+        stack.push(1);
+        stack.push(2);
+        let _ = stack[2];
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 0 but the index is 0")]
+    fn index_on_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        let _ = stack[0];
+    }
+}
+
+#[cfg(test)]
+mod into_list_tests {
+    use super::test_support::CloneCounter;
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn into_list_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert_eq!(stack.into_list(), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn into_list_filled_stack() {
+        let mut stack = Stack::empty();
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+        assert_eq!(stack.into_list(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn into_list_moves_uniquely_owned_values() {
+        let clones = Cell::new(0);
+        let mut stack = Stack::empty();
+        for value in 1..=3 {
+            stack.push(CloneCounter::new(value, &clones));
+        }
+
+        let values: Vec<u32> = stack.into_list().iter().map(|item| item.value).collect();
+        assert_eq!(values, vec![3, 2, 1]);
+        assert_eq!(clones.get(), 0);
+    }
+
+    #[test]
+    fn into_list_clones_shared_values() {
+        let clones = Cell::new(0);
+        let mut stack = Stack::empty();
+        for value in 1..=3 {
+            stack.push(CloneCounter::new(value, &clones));
+        }
+        let cloned_stack = stack.clone();
+        stack.push(CloneCounter::new(4, &clones));
+
+        let values: Vec<u32> = stack.into_list().iter().map(|item| item.value).collect();
+        assert_eq!(values, vec![4, 3, 2, 1]);
+        assert_eq!(clones.get(), 3); // only the values shared with cloned_stack
+        assert_eq!(cloned_stack.len(), 3);
+    }
+}
+
+#[cfg(test)]
+mod partition_tests {
+    use super::*;
+
+    #[test]
+    fn partition_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        let (matching, non_matching) = stack.partition(|_| true);
+        assert!(matching.is_empty());
+        assert!(non_matching.is_empty());
+    }
+
+    #[test]
+    fn partition_all_matching() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        let (matching, non_matching) = stack.partition(|value| *value > 0);
+        assert_eq!(matching.to_list(), vec![3, 2, 1]);
+        assert!(non_matching.is_empty());
+    }
+
+    #[test]
+    fn partition_none_matching() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        let (matching, non_matching) = stack.partition(|value| *value > 10);
+        assert!(matching.is_empty());
+        assert_eq!(non_matching.to_list(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn partition_mixed() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3, 4, 5, 6]);
+        let (matching, non_matching) = stack.partition(|value| value % 3 == 0);
+        assert_eq!(matching.to_list(), vec![6, 3]);
+        assert_eq!(non_matching.to_list(), vec![5, 4, 2, 1]);
+        assert_eq!(stack.to_list(), vec![6, 5, 4, 3, 2, 1]);
+    }
+}
+
+#[cfg(test)]
+mod pop_with_tests {
+    use super::*;
+
+    #[test]
+    fn pop_with_on_empty_stack() {
+        let mut stack: Stack<u32> = Stack::empty();
+        let mut calls = 0;
+        assert_eq!(stack.pop_with(|_| calls += 1), None);
+        assert_eq!(calls, 0);
+    }
+
+    #[test]
+    fn pop_with_on_filled_stack() {
+        let mut stack = Stack::empty();
+        stack.push(1);
+        stack.push(2);
+
+        let mut calls = 0;
+        let mut seen = None;
+        let result = stack.pop_with(|value| {
+            calls += 1;
+            seen = Some(*value);
+        });
+        assert_eq!(result, Some(2));
+        assert_eq!(calls, 1);
+        assert_eq!(seen, Some(2));
+        assert_eq!(stack.to_list(), vec![1]);
+    }
+}
+
+#[cfg(test)]
+mod swap_remove_tests {
+    use super::test_support::stack_of;
+    use super::*;
+
+    #[test]
+    fn swap_remove_top_is_pop() {
+        let mut stack = stack_of([1, 2, 3, 4, 5]);
+        assert_eq!(stack.swap_remove(0), Some(5));
+        assert_eq!(stack.to_list(), vec![4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn swap_remove_middle() {
+        let mut stack = stack_of([1, 2, 3, 4, 5]);
+        assert_eq!(stack.swap_remove(2), Some(3));
+        assert_eq!(stack.len(), 4);
+        assert_eq!(stack.to_list(), vec![4, 5, 2, 1]);
+    }
+
+    #[test]
+    fn swap_remove_bottom() {
+        let mut stack = stack_of([1, 2, 3, 4, 5]);
+        assert_eq!(stack.swap_remove(4), Some(1));
+        assert_eq!(stack.to_list(), vec![4, 3, 2, 5]);
+    }
+
+    #[test]
+    fn swap_remove_out_of_bounds() {
+        let mut stack = stack_of([1, 2, 3, 4, 5]);
+        assert_eq!(stack.swap_remove(5), None);
+        assert_eq!(stack.to_list(), vec![5, 4, 3, 2, 1]);
+
+        let mut empty_stack: Stack<u32> = Stack::empty();
+        assert_eq!(empty_stack.swap_remove(0), None);
+    }
+
+    #[test]
+    fn swap_remove_keeps_nodes_below_index() {
+        let mut stack = stack_of([1, 2, 3, 4, 5]);
+        let bottom_node = stack.iter().nth(3).unwrap() as *const u32;
+        assert_eq!(stack.swap_remove(1), Some(4));
+        assert_eq!(stack.to_list(), vec![5, 3, 2, 1]);
+        assert!(std::ptr::eq(stack.iter().nth(2).unwrap(), bottom_node));
+    }
+
+    #[test]
+    fn swap_remove_on_shared_stack() {
+        let mut stack = stack_of([1, 2, 3, 4, 5]);
+        let cloned_stack = stack.clone();
+        assert_eq!(stack.swap_remove(3), Some(2));
+        assert_eq!(stack.to_list(), vec![4, 3, 5, 1]);
+        assert_eq!(cloned_stack.to_list(), vec![5, 4, 3, 2, 1]);
+    }
+}
+
+#[cfg(test)]
+mod rotate_tests {
+    use super::test_support::stack_of;
+    use super::*;
+
+    #[test]
+    fn rotate_empty_stack() {
+        let mut stack: Stack<u32> = Stack::empty();
+        stack.rotate_left(3);
+        assert!(stack.is_empty());
+        stack.rotate_right(3);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn rotate_single_value_stack() {
+        let mut stack = Stack::new(1);
+        stack.rotate_left(3);
+        assert_eq!(stack.to_list(), vec![1]);
+        stack.rotate_right(3);
+        assert_eq!(stack.to_list(), vec![1]);
+    }
+
+    #[test]
+    fn rotate_left_by_one() {
+        let mut stack = stack_of([1, 2, 3, 4, 5]);
+        stack.rotate_left(1);
+        assert_eq!(stack.to_list(), vec![4, 3, 2, 1, 5]);
+    }
+
+    #[test]
+    fn rotate_left_by_size() {
+        let mut stack = stack_of([1, 2, 3, 4, 5]);
+        stack.rotate_left(5);
+        assert_eq!(stack.to_list(), vec![5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn rotate_left_by_more_than_size() {
+        let mut stack = stack_of([1, 2, 3, 4, 5]);
+        stack.rotate_left(7);
+        assert_eq!(stack.len(), 5);
+        assert_eq!(stack.to_list(), vec![3, 2, 1, 5, 4]);
+    }
+
+    #[test]
+    fn rotate_right_by_one() {
+        let mut stack = stack_of([1, 2, 3, 4, 5]);
+        stack.rotate_right(1);
+        assert_eq!(stack.to_list(), vec![1, 5, 4, 3, 2]);
+    }
+
+    #[test]
+    fn rotate_right_by_size() {
+        let mut stack = stack_of([1, 2, 3, 4, 5]);
+        stack.rotate_right(5);
+        assert_eq!(stack.to_list(), vec![5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn rotate_right_by_more_than_size() {
+        let mut stack = stack_of([1, 2, 3, 4, 5]);
+        stack.rotate_right(7);
+        assert_eq!(stack.len(), 5);
+        assert_eq!(stack.to_list(), vec![2, 1, 5, 4, 3]);
+    }
+
+    #[test]
+    fn rotate_left_then_right_is_identity() {
+        let mut stack = stack_of([1, 2, 3, 4, 5]);
+        stack.rotate_left(2);
+        stack.rotate_right(2);
+        assert_eq!(stack.to_list(), vec![5, 4, 3, 2, 1]);
+    }
+}
+
+#[cfg(test)]
+mod insert_sorted_tests {
+    use super::test_support::{Item, stack_of};
+    use super::*;
+
+    #[test]
+    fn insert_sorted_into_empty_stack() {
+        let mut stack = Stack::empty();
+        stack.insert_sorted(1);
+        assert_eq!(stack.to_list(), vec![1]);
+    }
+
+    #[test]
+    fn insert_sorted_new_minimum() {
+        let mut stack = stack_of([40, 30, 20, 10]);
+        stack.insert_sorted(5);
+        assert_eq!(stack.to_list(), vec![5, 10, 20, 30, 40]);
+    }
+
+    #[test]
+    fn insert_sorted_in_the_middle() {
+        let mut stack = stack_of([40, 30, 20, 10]);
+        stack.insert_sorted(25);
+        assert_eq!(stack.len(), 5);
+        assert_eq!(stack.to_list(), vec![10, 20, 25, 30, 40]);
+    }
+
+    #[test]
+    fn insert_sorted_new_maximum() {
+        let mut stack = stack_of([40, 30, 20, 10]);
+        stack.insert_sorted(50);
+        assert_eq!(stack.to_list(), vec![10, 20, 30, 40, 50]);
+    }
+
+    #[test]
+    fn insert_sorted_after_equal_values() {
+        let mut stack = Stack::empty();
+        stack.push(Item(2, "c"));
+        stack.push(Item(1, "a"));
+        stack.insert_sorted(Item(1, "b"));
+
+        let labels: Vec<_> = stack.to_list().iter().map(|item| item.1).collect();
+        assert_eq!(labels, vec!["a", "b", "c"]);
+    }
+}
+
+#[cfg(test)]
+mod merge_sorted_tests {
+    use super::test_support::Item;
+    use super::*;
+
+    #[test]
+    fn merge_sorted_empty_stacks() {
+        let stack: Stack<u32> = Stack::empty();
+        assert!(stack.merge_sorted(Stack::empty()).is_empty());
+    }
+
+    #[test]
+    fn merge_sorted_with_empty_stack() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![3, 2, 1]);
+        assert_eq!(stack.merge_sorted(Stack::empty()).to_list(), vec![1, 2, 3]);
+
+        let mut other = Stack::empty();
+        other.push_many(vec![3, 2, 1]);
+        assert_eq!(Stack::empty().merge_sorted(other).to_list(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn merge_sorted_filled_stacks() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![9, 4, 2]);
+        let mut other = Stack::empty();
+        other.push_many(vec![10, 8, 3, 1]);
+
+        let merged = stack.merge_sorted(other);
+        assert_eq!(merged.len(), 7);
+        assert_eq!(merged.to_list(), vec![1, 2, 3, 4, 8, 9, 10]);
+    }
+
+    #[test]
+    fn merge_sorted_is_stable() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![Item(2, "a2"), Item(1, "a1")]);
+        let mut other = Stack::empty();
+        other.push_many(vec![Item(2, "b2"), Item(1, "b1")]);
+
+        let labels: Vec<_> = stack
+            .merge_sorted(other)
+            .to_list()
+            .iter()
+            .map(|item| item.1)
+            .collect();
+        assert_eq!(labels, vec!["a1", "b1", "a2", "b2"]);
+    }
+}
+
+#[cfg(test)]
+mod sort_tests {
+    use super::*;
+
+    #[test]
+    fn sort_empty_stack() {
+        let mut stack: Stack<u32> = Stack::empty();
+        stack.sort();
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn sort_shuffled_stack() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![4, 1, 5, 3, 2]);
+        stack.sort();
+        assert_eq!(stack.len(), 5);
+        assert_eq!(stack.to_list(), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn sort_already_sorted_stack() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![3, 2, 2, 1]);
+        stack.sort();
+        assert_eq!(stack.to_list(), vec![1, 2, 2, 3]);
+        stack.sort();
+        assert_eq!(stack.to_list(), vec![1, 2, 2, 3]);
+    }
+}
+
+#[cfg(test)]
+mod scan_tests {
+    use super::*;
+
+    #[test]
+    fn scan_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert!(stack.scan(0, |sum, value| sum + value).is_empty());
+    }
+
+    #[test]
+    fn scan_running_sums() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![4, 3, 2, 1]);
+        let sums = stack.scan(0, |sum, value| sum + value);
+        assert_eq!(sums.len(), 4);
+        assert_eq!(sums.to_list(), vec![1, 3, 6, 10]);
+        assert_eq!(stack.to_list(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn scan_into_another_type() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec!['c', 'b', 'a']);
+        let prefixes = stack.scan(String::new(), |prefix, value| format!("{prefix}{value}"));
+        assert_eq!(prefixes.to_list(), vec!["a", "ab", "abc"]);
+    }
+}
+
+#[cfg(test)]
+mod enumerate_tests {
+    use super::*;
+
+    #[test]
+    fn enumerate_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert!(stack.enumerate().is_empty());
+    }
+
+    #[test]
+    fn enumerate_filled_stack() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![10, 20, 30]);
+        let enumerated = stack.enumerate();
+        assert_eq!(enumerated.len(), 3);
+        assert_eq!(enumerated.peek(), Some((0, 30)));
+        assert_eq!(enumerated.to_list(), vec![(0, 30), (1, 20), (2, 10)]);
+    }
+}
+
+#[cfg(test)]
+mod intersperse_tests {
+    use super::*;
+
+    #[test]
+    fn intersperse_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert!(stack.intersperse(0).is_empty());
+    }
+
+    #[test]
+    fn intersperse_single_value_stack() {
+        let stack = Stack::new(1);
+        assert_eq!(stack.intersperse(0).to_list(), vec![1]);
+    }
+
+    #[test]
+    fn intersperse_filled_stack() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        let interspersed = stack.intersperse(0);
+        assert_eq!(interspersed.len(), 5);
+        assert_eq!(interspersed.to_list(), vec![3, 0, 2, 0, 1]);
+        assert_eq!(stack.to_list(), vec![3, 2, 1]);
+    }
+}
+
+#[cfg(test)]
+mod take_while_tests {
+    use super::test_support::stack_of;
+    use super::*;
+
+    #[test]
+    fn take_while_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert!(stack.take_while(|_| true).is_empty());
+    }
+
+    #[test]
+    fn take_while_stops_partway() {
+        let stack = stack_of([1, 5, 2, 3]);
+        assert_eq!(stack.take_while(|value| *value < 4).to_list(), vec![3, 2]);
+        assert_eq!(stack.to_list(), vec![3, 2, 5, 1]);
+    }
+
+    #[test]
+    fn take_while_matches_everything() {
+        let stack = stack_of([1, 5, 2, 3]);
+        assert_eq!(stack.take_while(|_| true).to_list(), vec![3, 2, 5, 1]);
+    }
+
+    #[test]
+    fn take_while_matches_nothing() {
+        let stack = stack_of([1, 5, 2, 3]);
+        assert!(stack.take_while(|value| *value > 10).is_empty());
+    }
+}
+
+#[cfg(test)]
+mod skip_while_tests {
+    use super::test_support::stack_of;
+    use super::*;
+
+    #[test]
+    fn skip_while_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert!(stack.skip_while(|_| true).is_empty());
+    }
+
+    #[test]
+    fn skip_while_stops_partway() {
+        let stack = stack_of([1, 5, 2, 3]);
+        assert_eq!(stack.skip_while(|value| *value < 4).to_list(), vec![5, 1]);
+        assert_eq!(stack.to_list(), vec![3, 2, 5, 1]);
+    }
+
+    #[test]
+    fn skip_while_matches_everything() {
+        let stack = stack_of([1, 5, 2, 3]);
+        assert!(stack.skip_while(|_| true).is_empty());
+    }
+
+    #[test]
+    fn skip_while_matches_nothing() {
+        let stack = stack_of([1, 5, 2, 3]);
+        let skipped = stack.skip_while(|value| *value > 10);
+        assert_eq!(skipped.to_list(), vec![3, 2, 5, 1]);
+        assert_eq!(stack.head_strong_count(), 2); // head shared by both stacks
+    }
+}
+
+#[cfg(test)]
+mod step_by_tests {
+    use super::test_support::stack_of;
+    use super::*;
+
+    #[test]
+    fn step_by_one_copies_everything() {
+        let stack = stack_of(1..=5);
+        assert_eq!(stack.step_by(1).to_list(), vec![5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn step_by_two() {
+        let stack = stack_of(1..=5);
+        assert_eq!(stack.step_by(2).to_list(), vec![5, 3, 1]);
+    }
+
+    #[test]
+    fn step_by_more_than_size() {
+        let stack = stack_of(1..=5);
+        assert_eq!(stack.step_by(10).to_list(), vec![5]);
+    }
+
+    #[test]
+    fn step_by_on_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert!(stack.step_by(2).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "step must be greater than zero")]
+    fn step_by_zero() {
+        let stack = stack_of(1..=5);
+        stack.step_by(0);
+    }
+}
+
+#[cfg(test)]
+mod checked_pop_tests {
+    use super::*;
+
+    #[test]
+    fn checked_pop_on_filled_stack() {
+        let mut stack = Stack::empty();
+        stack.push(1);
+        stack.push(2);
+        assert_eq!(stack.checked_pop(), Ok(2));
+        assert_eq!(stack.to_list(), vec![1]);
+    }
+
+    #[test]
+    fn checked_pop_on_empty_stack() {
+        let mut stack: Stack<u32> = Stack::empty();
+        assert_eq!(stack.checked_pop(), Err(EmptyStackError));
+    }
+
+    #[test]
+    fn checked_pop_with_question_mark() {
+        fn pop_twice(stack: &mut Stack<u32>) -> Result<u32, Box<dyn Error>> {
+            Ok(stack.checked_pop()? + stack.checked_pop()?)
+        }
+
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        assert_eq!(pop_twice(&mut stack).unwrap(), 5);
+
+        let error = pop_twice(&mut stack).unwrap_err();
+        assert_eq!(error.to_string(), "stack is empty");
+    }
+
+    #[test]
+    fn empty_stack_error_display() {
+        assert_eq!(EmptyStackError.to_string(), "stack is empty");
+    }
+}
+
+#[cfg(test)]
+mod fill_tests {
+    use super::*;
+
+    #[test]
+    fn fill_empty_stack() {
+        let mut stack = Stack::empty();
+        stack.fill(7, 3);
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.to_list(), vec![7, 7, 7]);
+    }
+
+    #[test]
+    fn fill_filled_stack() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2]);
+        stack.fill(0, 2);
+        assert_eq!(stack.len(), 4);
+        assert_eq!(stack.to_list(), vec![0, 0, 2, 1]);
+    }
+
+    #[test]
+    fn fill_zero_times() {
+        let mut stack = Stack::new(1);
+        stack.fill(0, 0);
+        assert_eq!(stack.to_list(), vec![1]);
+    }
+}
+
+#[cfg(test)]
+mod from_fn_tests {
+    use super::*;
+
+    #[test]
+    fn from_fn_with_zero_values() {
+        let stack: Stack<usize> = Stack::from_fn(0, |index| index);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn from_fn_with_one_value() {
+        let stack = Stack::from_fn(1, |index| index + 100);
+        assert_eq!(stack.to_list(), vec![100]);
+    }
+
+    #[test]
+    fn from_fn_with_several_values() {
+        let stack = Stack::from_fn(4, |index| index * 10);
+        assert_eq!(stack.len(), 4);
+        assert_eq!(stack.peek(), Some(30)); // the last index is the head
+        assert_eq!(stack.to_list(), vec![30, 20, 10, 0]);
+    }
+
+    #[test]
+    fn from_fn_calls_in_index_order() {
+        let mut calls = Vec::new();
+        let _stack = Stack::from_fn(3, |index| {
+            calls.push(index);
+            index
+        });
+        assert_eq!(calls, vec![0, 1, 2]);
+    }
+}
+
+#[cfg(test)]
+mod collect_into_tests {
+    use super::*;
+
+    #[test]
+    fn collect_into_empty_vec() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        let mut out = Vec::new();
+        stack.collect_into(&mut out);
+        assert_eq!(out, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn collect_into_filled_vec() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        let mut out = vec![10, 20];
+        stack.collect_into(&mut out);
+        assert_eq!(out, vec![10, 20, 3, 2, 1]);
+    }
+
+    #[test]
+    fn collect_into_from_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        let mut out = vec![10];
+        stack.collect_into(&mut out);
+        assert_eq!(out, vec![10]);
+    }
+
+    #[test]
+    fn collect_into_reuses_capacity() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        let mut out = Vec::with_capacity(8);
+        let capacity = out.capacity();
+        stack.collect_into(&mut out);
+        assert_eq!(out.capacity(), capacity);
+    }
+}
+
+#[cfg(test)]
+mod compact_tests {
+    use super::*;
+
+    #[test]
+    fn compact_empty_stack() {
+        let mut stack: Stack<u32> = Stack::empty();
+        stack.compact();
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn compact_keeps_order() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3, 4]);
+        stack.swap_remove(1);
+        stack.rotate_left(1);
+        let list = stack.to_list();
+
+        stack.compact();
+        assert_eq!(stack.to_list(), list);
+    }
+
+    #[test]
+    fn compact_unshares_nodes() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        let cloned_stack = stack.clone();
+        assert_eq!(stack.head_strong_count(), 2);
+
+        stack.compact();
+        assert_eq!(stack.head_strong_count(), 1);
+        assert_eq!(cloned_stack.head_strong_count(), 1);
+        assert_eq!(stack.to_list(), vec![3, 2, 1]);
+        assert_eq!(cloned_stack.to_list(), vec![3, 2, 1]);
+    }
+}
+
+#[cfg(test)]
+mod snapshot_tests {
+    use super::*;
+
+    #[test]
+    fn snapshot_of_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        let snapshot = stack.snapshot();
+        assert!(snapshot.is_empty());
+        assert_eq!(snapshot.len(), 0);
+        assert_eq!(snapshot.to_list(), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn snapshot_of_filled_stack() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        let snapshot = stack.snapshot();
+        assert_eq!(snapshot.len(), 3);
+        assert_eq!(snapshot.iter().collect::<Vec<_>>(), vec![&3, &2, &1]);
+        assert_eq!(snapshot.to_list(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn snapshot_is_stable_across_push_and_pop() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        let snapshot = stack.snapshot();
+
+        stack.pop();
+        stack.pop();
+        stack.push(10);
+        stack.push(20);
+
+        assert_eq!(stack.to_list(), vec![20, 10, 1]);
+        assert_eq!(snapshot.to_list(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn snapshot_shares_nodes() {
+        let node: Rc<Node<_>> = Rc::new(Node::new(1));
+        let stack: Stack<_> = Stack {
+            head: Some(Rc::clone(&node)),
+        };
+        assert_eq!(Rc::strong_count(&node), 2); // node itself, and referenced by stack
+
+        let snapshot = stack.snapshot();
+        let cloned_snapshot = snapshot.clone();
+        assert_eq!(Rc::strong_count(&node), 4); // also referenced by both snapshots
+
+        drop(snapshot);
+        drop(cloned_snapshot);
+        assert_eq!(Rc::strong_count(&node), 2);
+    }
+}
+
+#[cfg(test)]
+mod try_fold_tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn try_fold_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        let result: Result<u32, ()> = stack.try_fold(7, |sum, value| Ok(sum + value));
+        assert_eq!(result, Ok(7));
+    }
+
+    #[test]
+    fn try_fold_every_step_succeeds() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3, 4]);
+        let result: Result<u32, ()> = stack.try_fold(0, |sum, value| Ok(sum + value));
+        assert_eq!(result, Ok(10));
+    }
+
+    #[test]
+    fn try_fold_stops_at_first_error() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3, 4]);
+        assert_eq!(stack.to_list(), vec![4, 3, 2, 1]);
+
+        let steps = Cell::new(0);
+        let result = stack.try_fold(0, |sum, value| {
+            steps.set(steps.get() + 1);
+            if *value == 3 {
+                Err(format!("invalid value {value}"))
+            } else {
+                Ok(sum + value)
+            }
+        });
+        assert_eq!(result, Err(String::from("invalid value 3")));
+        assert_eq!(steps.get(), 2);
+    }
+}
+
+#[cfg(test)]
+mod len_tests {
+    use super::*;
+
+    #[test]
+    fn len_of_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert_eq!(stack.len(), 0);
+    }
+
+    #[test]
+    fn len_of_filled_stack() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        assert_eq!(stack.len(), 3);
+        stack.pop();
+        assert_eq!(stack.len(), 2);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn len_equals_size() {
+        let mut stack = Stack::empty();
+        assert_eq!(stack.len(), stack.size() as usize);
+        for value in 0..5 {
+            stack.push(value);
+            assert_eq!(stack.len(), stack.size() as usize);
+        }
+    }
+
+    #[test]
+    fn len_as_index_bound() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+
+        let mut values = Vec::new();
+        for index in 0..stack.len() {
+            values.push(stack[index]);
+        }
+        assert_eq!(values, stack.to_list());
+    }
+}
+
+#[cfg(test)]
+mod repeat_tests {
+    use super::test_support::stack_of;
+    use super::*;
+
+    #[test]
+    fn repeat_zero_times() {
+        let stack = stack_of([1, 2]);
+        assert!(stack.repeat(0).is_empty());
+    }
+
+    #[test]
+    fn repeat_once() {
+        let stack = stack_of([1, 2]);
+        assert_eq!(stack.repeat(1).to_list(), vec![2, 1]);
+    }
+
+    #[test]
+    fn repeat_three_times() {
+        let stack = stack_of([1, 2]);
+        let repeated = stack.repeat(3);
+        assert_eq!(repeated.len(), 6);
+        assert_eq!(repeated.to_list(), vec![2, 1, 2, 1, 2, 1]);
+        assert_eq!(stack.to_list(), vec![2, 1]);
+    }
+
+    #[test]
+    fn repeat_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert!(stack.repeat(3).is_empty());
+    }
+}
+
+#[cfg(test)]
+mod dedup_by_key_tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Task {
+        id: u32,
+        attempt: u32,
+    }
+
+    fn task(id: u32, attempt: u32) -> Task {
+        Task { id, attempt }
+    }
+
+    #[test]
+    fn dedup_by_key_empty_stack() {
+        let mut stack: Stack<Task> = Stack::empty();
+        stack.dedup_by_key(|task| task.id);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn dedup_by_key_with_runs() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![
+            task(1, 3),
+            task(2, 2),
+            task(2, 1),
+            task(1, 2),
+            task(1, 1),
+        ]);
+        assert_eq!(stack.len(), 5);
+
+        stack.dedup_by_key(|task| task.id);
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.to_list(), vec![task(1, 1), task(2, 1), task(1, 3)]);
+    }
+
+    #[test]
+    fn dedup_by_key_without_duplicates() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![task(3, 1), task(2, 1), task(1, 1)]);
+
+        stack.dedup_by_key(|task| task.id);
+        assert_eq!(stack.to_list(), vec![task(1, 1), task(2, 1), task(3, 1)]);
+    }
+}
+
+#[cfg(test)]
+mod by_key_tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Player {
+        name: &'static str,
+        score: u32,
+    }
+
+    fn players() -> Stack<Player> {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![
+            Player {
+                name: "dave",
+                score: 10,
+            },
+            Player {
+                name: "carol",
+                score: 30,
+            },
+            Player {
+                name: "bob",
+                score: 10,
+            },
+            Player {
+                name: "alice",
+                score: 30,
+            },
+        ]);
+        stack
+    }
+
+    #[test]
+    fn by_key_on_empty_stack() {
+        let stack: Stack<Player> = Stack::empty();
+        assert_eq!(stack.max_by_key(|player| player.score), None);
+        assert_eq!(stack.min_by_key(|player| player.score), None);
+    }
+
+    #[test]
+    fn max_by_key_selects_first_of_ties() {
+        let stack = players();
+        let max = stack.max_by_key(|player| player.score).unwrap();
+        assert_eq!(max.name, "alice");
+    }
+
+    #[test]
+    fn min_by_key_selects_first_of_ties() {
+        let stack = players();
+        let min = stack.min_by_key(|player| player.score).unwrap();
+        assert_eq!(min.name, "bob");
+    }
+
+    #[test]
+    fn by_key_on_single_value_stack() {
+        let stack = Stack::new(Player {
+            name: "alice",
+            score: 1,
+        });
+        assert_eq!(
+            stack.max_by_key(|player| player.score).unwrap().name,
+            "alice"
+        );
+        assert_eq!(
+            stack.min_by_key(|player| player.score).unwrap().name,
+            "alice"
+        );
+    }
+}
+
+#[cfg(test)]
+mod reduce_tests {
+    use super::*;
+
+    #[test]
+    fn reduce_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert_eq!(stack.reduce(|sum, value| sum + value), None);
+    }
+
+    #[test]
+    fn reduce_single_value_stack() {
+        let stack = Stack::new(5);
+        assert_eq!(stack.reduce(|sum, value| sum + value), Some(5));
+    }
+
+    #[test]
+    fn reduce_with_sum() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3, 4]);
+        assert_eq!(stack.reduce(|sum, value| sum + value), Some(10));
+    }
+
+    #[test]
+    fn reduce_with_max() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![3, 9, 1, 4]);
+        assert_eq!(stack.reduce(|max, value| max.max(*value)), Some(9));
+    }
+
+    #[test]
+    fn reduce_starts_from_head() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![
+            String::from("c"),
+            String::from("b"),
+            String::from("a"),
+        ]);
+        assert_eq!(
+            stack.reduce(|joined, value| joined + value),
+            Some(String::from("abc"))
+        );
+    }
+}
+
+#[cfg(test)]
+mod raw_tests {
+    use super::*;
+
+    #[test]
+    fn raw_round_trip_of_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        let pointer = stack.into_raw();
+        assert!(pointer.is_null());
+
+        let stack = unsafe { Stack::from_raw(pointer) };
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn raw_round_trip_of_filled_stack() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        let pointer = stack.into_raw();
+        assert!(!pointer.is_null());
+
+        let stack = unsafe { Stack::from_raw(pointer) };
+        assert_eq!(stack.to_list(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn raw_round_trip_does_not_leak() {
+        let node: Rc<Node<_>> = Rc::new(Node::new(1));
+        let stack: Stack<_> = Stack {
+            head: Some(Rc::new(Node::new_with_next(2, Rc::clone(&node)))),
+        };
+        assert_eq!(Rc::strong_count(&node), 2); // node itself, and referenced by the head
+
+        let pointer = stack.into_raw();
+        assert_eq!(Rc::strong_count(&node), 2); // still owned through the pointer
+
+        let stack = unsafe { Stack::from_raw(pointer) };
+        assert_eq!(Rc::strong_count(&node), 2);
+
+        drop(stack);
+        assert_eq!(Rc::strong_count(&node), 1); // node itself, as the chain has been freed
+    }
+
+    #[test]
+    #[should_panic(expected = "head node is shared with another stack")]
+    fn into_raw_with_shared_head() {
+        let stack = Stack::new(1);
+        let _cloned_stack = stack.clone();
+        stack.into_raw();
+    }
+}
+
+#[cfg(test)]
+mod pop_back_tests {
+    use super::*;
+
+    #[test]
+    fn pop_back_on_empty_stack() {
+        let mut stack: Stack<u32> = Stack::empty();
+        assert_eq!(stack.pop_back(), None);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn pop_back_on_stack_with_one_element() {
+        let mut stack = Stack::new(1);
+        assert_eq!(stack.pop_back(), Some(1));
+        assert!(stack.is_empty());
+        assert_eq!(stack.pop_back(), None);
+    }
+
+    #[test]
+    fn pop_back_on_stack_with_several_elements() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+
+        assert_eq!(stack.pop_back(), Some(1));
+        assert_eq!(stack.len(), 2);
+        assert_eq!(stack.to_list(), vec![3, 2]);
+
+        assert_eq!(stack.pop_back(), Some(2));
+        assert_eq!(stack.pop_back(), Some(3));
+        assert_eq!(stack.pop_back(), None);
+    }
+
+    #[test]
+    fn pop_back_on_shared_stack() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        let cloned_stack = stack.clone();
+
+        assert_eq!(stack.pop_back(), Some(1));
+        assert_eq!(stack.to_list(), vec![3, 2]);
+        assert_eq!(cloned_stack.to_list(), vec![3, 2, 1]);
+    }
+}
+
+#[cfg(test)]
+mod swap_tests {
+    use super::test_support::stack_of;
+    use super::*;
+
+    #[test]
+    fn swap_adjacent() {
+        let mut stack = stack_of([1, 2, 3, 4, 5]);
+        stack.swap(1, 2);
+        assert_eq!(stack.to_list(), vec![5, 3, 4, 2, 1]);
+    }
+
+    #[test]
+    fn swap_distant() {
+        let mut stack = stack_of([1, 2, 3, 4, 5]);
+        stack.swap(4, 0);
+        assert_eq!(stack.len(), 5);
+        assert_eq!(stack.to_list(), vec![1, 4, 3, 2, 5]);
+    }
+
+    #[test]
+    fn swap_with_itself() {
+        let mut stack = stack_of([1, 2, 3, 4, 5]);
+        stack.swap(2, 2);
+        assert_eq!(stack.to_list(), vec![5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn swap_on_shared_stack() {
+        let mut stack = stack_of([1, 2, 3, 4, 5]);
+        let cloned_stack = stack.clone();
+        stack.swap(1, 3);
+        assert_eq!(stack.to_list(), vec![5, 2, 3, 4, 1]);
+        assert_eq!(cloned_stack.to_list(), vec![5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 5 but the index is 5")]
+    fn swap_out_of_bounds() {
+        let mut stack = stack_of([1, 2, 3, 4, 5]);
+        stack.swap(0, 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 0 but the index is 0")]
+    fn swap_on_empty_stack() {
+        let mut stack: Stack<u32> = Stack::empty();
+        stack.swap(0, 0);
+    }
+}
+
+#[cfg(test)]
+mod top_or_tests {
+    use super::*;
+
+    #[test]
+    fn top_or_on_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert_eq!(stack.top_or(7), 7);
+    }
+
+    #[test]
+    fn top_or_on_filled_stack() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2]);
+        assert_eq!(stack.top_or(7), 2);
+        assert_eq!(stack.len(), 2);
+    }
+
+    #[test]
+    fn top_or_else_on_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert_eq!(stack.top_or_else(|| 7), 7);
+    }
+
+    #[test]
+    fn top_or_else_on_filled_stack() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2]);
+        let mut calls = 0;
+        assert_eq!(
+            stack.top_or_else(|| {
+                calls += 1;
+                7
+            }),
+            2
+        );
+        assert_eq!(calls, 0);
+    }
+}
+
+#[cfg(test)]
+mod iter_indices_tests {
+    use super::*;
+
+    #[test]
+    fn iter_indices_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert_eq!(stack.iter_indices().next(), None);
+    }
+
+    #[test]
+    fn iter_indices_filled_stack() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![10, 20, 30]);
+        let pairs: Vec<_> = stack.iter_indices().collect();
+        assert_eq!(pairs, vec![(0, &30), (1, &20), (2, &10)]);
+    }
+
+    #[test]
+    fn iter_indices_match_index() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![10, 20, 30]);
+        for (index, value) in stack.iter_indices() {
+            assert_eq!(&stack[index], value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod head_ptr_tests {
+    use super::*;
+
+    fn walk(stack: &Stack<u32>) -> Vec<u32> {
+        let mut values = Vec::new();
+        let mut pointer = stack.head_ptr();
+        while let Some(node_pointer) = pointer {
+            let node = unsafe { &*node_pointer };
+            values.push(*node.value());
+            pointer = node.next().map(|next| next as *const Node<u32>);
+        }
+        values
+    }
+
+    #[test]
+    fn head_ptr_of_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert!(stack.head_ptr().is_none());
+    }
+
+    #[test]
+    fn head_ptr_points_to_head_node() {
+        let node: Rc<Node<_>> = Rc::new(Node::new(1));
+        let stack: Stack<_> = Stack {
+            head: Some(Rc::clone(&node)),
+        };
+        assert_eq!(stack.head_ptr(), Some(Rc::as_ptr(&node)));
+        assert_eq!(Rc::strong_count(&node), 2); // taking the pointer does not add a reference
+    }
+
+    #[test]
+    fn walk_chain_through_head_ptr() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3, 4]);
+        assert_eq!(walk(&stack), stack.to_list());
+
+        stack.pop();
+        assert_eq!(walk(&stack), vec![3, 2, 1]);
+    }
+}
+
+#[cfg(test)]
+mod eq_slice_tests {
+    use super::test_support::stack_of;
+    use super::*;
+
+    #[test]
+    fn eq_slice_empty() {
+        let stack: Stack<u32> = Stack::empty();
+        assert!(stack.eq_slice(&[]));
+        assert!(!stack.eq_slice(&[1]));
+    }
+
+    #[test]
+    fn eq_slice_equal() {
+        assert!(stack_of([1, 2, 3]).eq_slice(&[3, 2, 1]));
+    }
+
+    #[test]
+    fn eq_slice_shorter_slice() {
+        assert!(!stack_of([1, 2, 3]).eq_slice(&[3, 2]));
+    }
+
+    #[test]
+    fn eq_slice_longer_slice() {
+        assert!(!stack_of([1, 2, 3]).eq_slice(&[3, 2, 1, 0]));
+    }
+
+    #[test]
+    fn eq_slice_different_content() {
+        assert!(!stack_of([1, 2, 3]).eq_slice(&[3, 0, 1]));
+        assert!(!stack_of([1, 2, 3]).eq_slice(&[1, 2, 3]));
+    }
+}
+
+#[cfg(test)]
+mod rchunks_tests {
+    use super::*;
+
+    #[test]
+    fn rchunks_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert_eq!(stack.rchunks(2), Vec::<Vec<u32>>::new());
+    }
+
+    #[test]
+    fn rchunks_divisible() {
+        let mut stack = Stack::empty();
+        stack.push_many(1..=6);
+        assert_eq!(stack.rchunks(3), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    }
+
+    #[test]
+    fn rchunks_with_remainder() {
+        let mut stack = Stack::empty();
+        stack.push_many(1..=5);
+        assert_eq!(stack.rchunks(2), vec![vec![1, 2], vec![3, 4], vec![5]]);
+    }
+
+    #[test]
+    fn rchunks_single_chunk() {
+        let mut stack = Stack::empty();
+        stack.push_many(1..=3);
+        assert_eq!(stack.rchunks(5), vec![vec![1, 2, 3]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be greater than zero")]
+    fn rchunks_zero() {
+        let stack = Stack::new(1);
+        stack.rchunks(0);
+    }
+}
+
+#[cfg(test)]
+mod move_to_front_tests {
+    use super::test_support::stack_of;
+    use super::*;
+
+    #[test]
+    fn move_to_front_middle_value() {
+        let mut stack = stack_of([1, 2, 3, 4]);
+        assert!(stack.move_to_front(&2));
+        assert_eq!(stack.len(), 4);
+        assert_eq!(stack.to_list(), vec![2, 4, 3, 1]);
+    }
+
+    #[test]
+    fn move_to_front_bottom_value() {
+        let mut stack = stack_of([1, 2, 3, 4]);
+        assert!(stack.move_to_front(&1));
+        assert_eq!(stack.to_list(), vec![1, 4, 3, 2]);
+    }
+
+    #[test]
+    fn move_to_front_head_value() {
+        let mut stack = stack_of([1, 2, 3, 4]);
+        let head_pointer = stack.head_ptr();
+        assert!(stack.move_to_front(&4));
+        assert_eq!(stack.to_list(), vec![4, 3, 2, 1]);
+        assert_eq!(stack.head_ptr(), head_pointer); // the nodes are left untouched
+    }
+
+    #[test]
+    fn move_to_front_absent_value() {
+        let mut stack = stack_of([1, 2, 3, 4]);
+        assert!(!stack.move_to_front(&5));
+        assert_eq!(stack.to_list(), vec![4, 3, 2, 1]);
+
+        let mut empty_stack: Stack<u32> = Stack::empty();
+        assert!(!empty_stack.move_to_front(&1));
+    }
+
+    #[test]
+    fn move_to_front_first_match_only() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![5, 1, 5, 2]);
+        assert_eq!(stack.to_list(), vec![2, 5, 1, 5]);
+
+        assert!(stack.move_to_front(&5));
+        assert_eq!(stack.to_list(), vec![5, 2, 1, 5]);
+    }
+}
+
+#[cfg(test)]
+mod with_each_mut_tests {
+    use super::test_support::CloneCounter;
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn with_each_mut_empty_stack() {
+        let mut stack: Stack<u32> = Stack::empty();
+        let mut calls = 0;
+        stack.with_each_mut(|_| calls += 1);
+        assert_eq!(calls, 0);
+    }
+
+    #[test]
+    fn with_each_mut_scales_every_value() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        let head_pointer = stack.head_ptr();
+
+        stack.with_each_mut(|value| *value *= 2);
+        assert_eq!(stack.to_list(), vec![6, 4, 2]);
+        assert_eq!(stack.head_ptr(), head_pointer); // edited in place
+    }
+
+    #[test]
+    fn with_each_mut_on_shared_stack() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        let cloned_stack = stack.clone();
+
+        stack.with_each_mut(|value| *value += 10);
+        assert_eq!(stack.to_list(), vec![13, 12, 11]);
+        assert_eq!(cloned_stack.to_list(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn with_each_mut_does_not_clone_unique_nodes() {
+        let clones = Cell::new(0);
+        let mut stack = Stack::empty();
+        for value in 1..=3 {
+            stack.push(CloneCounter::new(value, &clones));
+        }
+
+        stack.with_each_mut(|counter| counter.value *= 2);
+        assert_eq!(clones.get(), 0);
+    }
+
+    #[test]
+    fn with_each_mut_copies_shared_node_and_every_node_below() {
+        let clones = Cell::new(0);
+        let mut stack = Stack::empty();
+        for value in 1..=3 {
+            stack.push(CloneCounter::new(value, &clones));
+        }
+        let cloned_stack = stack.clone();
+        stack.push(CloneCounter::new(4, &clones));
+
+        stack.with_each_mut(|counter| counter.value *= 2);
+        assert_eq!(clones.get(), 3); // the 3 values shared with cloned_stack
+        let values: Vec<u32> = stack.iter().map(|counter| counter.value).collect();
+        assert_eq!(values, vec![8, 6, 4, 2]);
+        let cloned_values: Vec<u32> = cloned_stack.iter().map(|counter| counter.value).collect();
+        assert_eq!(cloned_values, vec![3, 2, 1]);
+    }
+}
+
+#[cfg(test)]
+mod frequencies_tests {
+    use super::*;
+
+    #[test]
+    fn frequencies_of_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert!(stack.frequencies().is_empty());
+    }
+
+    #[test]
+    fn frequencies_with_repeated_values() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 1, 3, 1, 2]);
+
+        let frequencies = stack.frequencies();
+        assert_eq!(frequencies.len(), 3);
+        assert_eq!(frequencies[&1], 3);
+        assert_eq!(frequencies[&2], 2);
+        assert_eq!(frequencies[&3], 1);
+        assert_eq!(stack.len(), 6);
+    }
+}
+
+#[cfg(test)]
+mod is_sorted_tests {
+    use super::*;
+
+    #[test]
+    fn empty_stack_is_sorted() {
+        let stack: Stack<u32> = Stack::empty();
+        assert!(stack.is_sorted());
+    }
+
+    #[test]
+    fn single_value_stack_is_sorted() {
+        assert!(Stack::new(1).is_sorted());
+    }
+
+    #[test]
+    fn ascending_stack_is_sorted() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![5, 3, 3, 1]);
+        assert_eq!(stack.to_list(), vec![1, 3, 3, 5]);
+        assert!(stack.is_sorted());
+    }
+
+    #[test]
+    fn descending_stack_is_not_sorted() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 3, 5]);
+        assert_eq!(stack.to_list(), vec![5, 3, 1]);
+        assert!(!stack.is_sorted());
+    }
+
+    #[test]
+    fn stack_unsorted_at_the_bottom_is_not_sorted() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 5, 3, 2]);
+        assert_eq!(stack.to_list(), vec![2, 3, 5, 1]);
+        assert!(!stack.is_sorted());
+    }
+}
+
+#[cfg(test)]
+mod try_into_array_tests {
+    use super::*;
+
+    #[test]
+    fn try_into_array_with_exact_size() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        let [top, middle, bottom] = stack.try_into_array().ok().unwrap();
+        assert_eq!((top, middle, bottom), (3, 2, 1));
+    }
+
+    #[test]
+    fn try_into_array_of_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert_eq!(stack.try_into_array::<0>().ok(), Some([]));
+    }
+
+    #[test]
+    fn try_into_array_with_too_few_values() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2]);
+        let head_pointer = stack.head_ptr();
+
+        let stack = stack.try_into_array::<3>().err().unwrap();
+        assert_eq!(stack.head_ptr(), head_pointer);
+        assert_eq!(stack.to_list(), vec![2, 1]);
+    }
+
+    #[test]
+    fn try_into_array_with_too_many_values() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        let head_pointer = stack.head_ptr();
+
+        let stack = stack.try_into_array::<2>().err().unwrap();
+        assert_eq!(stack.head_ptr(), head_pointer);
+        assert_eq!(stack.to_list(), vec![3, 2, 1]);
+    }
+}
+
+#[cfg(test)]
+mod dedup_global_tests {
+    use super::*;
+
+    #[test]
+    fn dedup_global_empty_stack() {
+        let mut stack: Stack<u32> = Stack::empty();
+        stack.dedup_global();
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn dedup_global_without_duplicates() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        stack.dedup_global();
+        assert_eq!(stack.to_list(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn dedup_global_keeps_first_occurrences() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![4, 1, 2, 4, 3, 1, 1, 2]);
+        assert_eq!(stack.to_list(), vec![2, 1, 1, 3, 4, 2, 1, 4]);
+
+        stack.dedup_global();
+        assert_eq!(stack.to_list(), vec![2, 1, 3, 4]);
+        assert_eq!(stack.len(), 4);
+    }
+}
+
+#[cfg(test)]
+mod char_stack_tests {
+    use super::*;
+
+    #[test]
+    fn empty_string_round_trip() {
+        let stack = Stack::<char>::from_str("").unwrap();
+        assert!(stack.is_empty());
+        assert_eq!(stack.to_string_top_first(), "");
+    }
+
+    #[test]
+    fn from_str_places_last_char_on_head() {
+        let stack = Stack::<char>::from_str("héllo").unwrap();
+        assert_eq!(stack.peek(), Some('o'));
+        assert_eq!(stack.to_list(), vec!['o', 'l', 'l', 'é', 'h']);
+    }
+
+    #[test]
+    fn to_string_top_first_of_pushed_chars() {
+        let mut stack = Stack::empty();
+        stack.push('x');
+        stack.push('y');
+        assert_eq!(stack.to_string_top_first(), "yx");
+    }
+
+    #[test]
+    fn round_trip_reverses_string() {
+        let reversed = Stack::<char>::from_str("stack")
+            .unwrap()
+            .to_string_top_first();
+        assert_eq!(reversed, "kcats");
+        assert_eq!(
+            Stack::<char>::from_str(&reversed)
+                .unwrap()
+                .to_string_top_first(),
+            "stack"
+        );
+    }
+}
+
+#[cfg(test)]
+mod histogram_tests {
+    use super::*;
+
+    #[test]
+    fn histogram_of_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert!(stack.histogram(|value| value % 2).is_empty());
+    }
+
+    #[test]
+    fn histogram_by_parity() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3, 5, 7, 8]);
+
+        let histogram = stack.histogram(|value| value % 2);
+        assert_eq!(histogram.len(), 2);
+        assert_eq!(histogram[&0], 2);
+        assert_eq!(histogram[&1], 4);
+    }
+
+    #[test]
+    fn histogram_by_owned_key() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec!["apple", "avocado", "banana"]);
+
+        let histogram = stack.histogram(|value| value[..1].to_string());
+        assert_eq!(histogram["a"], 2);
+        assert_eq!(histogram["b"], 1);
+    }
+}
+
+#[cfg(test)]
+mod split_at_value_tests {
+    use super::test_support::stack_of;
+    use super::*;
+
+    #[test]
+    fn split_at_value_on_empty_stack() {
+        let mut stack: Stack<u32> = Stack::empty();
+        assert!(stack.split_at_value(&1).is_none());
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn split_at_value_at_head() {
+        let mut stack = stack_of([1, 2, 3, 4]);
+        let below = stack.split_at_value(&4).unwrap();
+        assert_eq!(stack.to_list(), vec![4]);
+        assert_eq!(below.to_list(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn split_at_value_in_middle() {
+        let mut stack = stack_of([1, 2, 3, 4]);
+        let below = stack.split_at_value(&2).unwrap();
+        assert_eq!(stack.to_list(), vec![4, 3, 2]);
+        assert_eq!(below.to_list(), vec![1]);
+    }
+
+    #[test]
+    fn split_at_value_at_bottom() {
+        let mut stack = stack_of([1, 2, 3, 4]);
+        let below = stack.split_at_value(&1).unwrap();
+        assert_eq!(stack.to_list(), vec![4, 3, 2, 1]);
+        assert!(below.is_empty());
+    }
+
+    #[test]
+    fn split_at_value_without_match() {
+        let mut stack = stack_of([1, 2, 3, 4]);
+        assert!(stack.split_at_value(&5).is_none());
+        assert_eq!(stack.to_list(), vec![4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn split_at_value_splits_at_first_match() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 1, 3]);
+        let below = stack.split_at_value(&1).unwrap();
+        assert_eq!(stack.to_list(), vec![3, 1]);
+        assert_eq!(below.to_list(), vec![2, 1]);
+    }
+
+    #[test]
+    fn split_at_value_on_shared_stack() {
+        let mut stack = stack_of([1, 2, 3, 4]);
+        let cloned_stack = stack.clone();
+        let below = stack.split_at_value(&3).unwrap();
+        assert_eq!(stack.to_list(), vec![4, 3]);
+        assert_eq!(below.to_list(), vec![2, 1]);
+        assert_eq!(cloned_stack.to_list(), vec![4, 3, 2, 1]);
+    }
+}
+
+#[cfg(test)]
+mod zip_with_tests {
+    use super::*;
+
+    #[test]
+    fn zip_with_empty_stack() {
+        let stack = Stack::new(1);
+        let other: Stack<u32> = Stack::empty();
+        assert!(stack.zip_with(&other, |a, b| a + b).is_empty());
+        assert!(other.zip_with(&stack, |a, b| a + b).is_empty());
+    }
+
+    #[test]
+    fn zip_with_sums_equal_length_stacks() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        let mut other = Stack::empty();
+        other.push_many(vec![10, 20, 30]);
+
+        let sums = stack.zip_with(&other, |a, b| a + b);
+        assert_eq!(sums.to_list(), vec![33, 22, 11]);
+    }
+
+    #[test]
+    fn zip_with_truncates_to_shorter_stack() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3, 4]);
+        let mut other = Stack::empty();
+        other.push_many(vec!["a", "b"]);
+
+        let pairs = stack.zip_with(&other, |number, letter| format!("{letter}{number}"));
+        assert_eq!(pairs.to_list(), vec!["b4", "a3"]);
+        assert_eq!(other.zip_with(&stack, |letter, _| *letter).len(), 2);
+    }
+}
+
+#[cfg(test)]
+mod chunk_fold_tests {
+    use super::*;
+
+    #[test]
+    fn chunk_fold_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert!(stack.chunk_fold(2, 0, |sum, value| sum + value).is_empty());
+    }
+
+    #[test]
+    fn chunk_fold_sums_chunks_with_short_last_chunk() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3, 4, 5]);
+        assert_eq!(
+            stack.chunk_fold(2, 0, |sum, value| sum + value),
+            vec![9, 5, 1]
+        );
+    }
+
+    #[test]
+    fn chunk_fold_with_exact_chunks() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3, 4]);
+        assert_eq!(stack.chunk_fold(2, 0, |sum, value| sum + value), vec![7, 3]);
+    }
+
+    #[test]
+    fn chunk_fold_restarts_from_init() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec!['a', 'b', 'c']);
+        let folded = stack.chunk_fold(2, String::from(">"), |mut string, value| {
+            string.push(*value);
+            string
+        });
+        assert_eq!(folded, vec![">cb", ">a"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be greater than zero")]
+    fn chunk_fold_with_zero_chunk_size() {
+        Stack::new(1).chunk_fold(0, 0, |sum, value| sum + value);
+    }
+}
+
+#[cfg(test)]
+mod remove_all_tests {
+    use super::*;
+
+    #[test]
+    fn remove_all_from_empty_stack() {
+        let mut stack: Stack<u32> = Stack::empty();
+        assert_eq!(stack.remove_all(|_| true), 0);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn remove_all_subset() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(stack.remove_all(|value| value % 3 != 0), 4);
+        assert_eq!(stack.to_list(), vec![6, 3]);
+    }
+
+    #[test]
+    fn remove_all_values() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        assert_eq!(stack.remove_all(|_| true), 3);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn remove_all_without_match() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        assert_eq!(stack.remove_all(|value| *value > 3), 0);
+        assert_eq!(stack.to_list(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn remove_all_calls_predicate_from_head() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3, 4]);
+        let mut visited = Vec::new();
+        stack.remove_all(|value| {
+            visited.push(*value);
+            visited.len() == 2
+        });
+        assert_eq!(visited, vec![4, 3, 2, 1]);
+        assert_eq!(stack.to_list(), vec![4, 2, 1]);
+    }
+}
+
+#[cfg(test)]
+mod from_reversed_vec_tests {
+    use super::*;
+
+    #[test]
+    fn from_reversed_vec_empty() {
+        let stack: Stack<u32> = Stack::from_reversed_vec(Vec::new());
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn from_reversed_vec_keeps_order() {
+        let stack = Stack::from_reversed_vec(vec![3, 2, 1]);
+        assert_eq!(stack.to_list(), vec![3, 2, 1]);
+        assert_eq!(stack.peek(), Some(3));
+    }
+
+    #[test]
+    fn from_reversed_vec_is_reverse_of_push_many() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        assert_eq!(
+            stack.to_list(),
+            Stack::from_reversed_vec(vec![3, 2, 1]).to_list()
+        );
+    }
+}
+
+#[cfg(test)]
+mod drain_bottom_while_tests {
+    use super::*;
+
+    #[test]
+    fn drain_bottom_while_empty_stack() {
+        let mut stack: Stack<u32> = Stack::empty();
+        assert!(stack.drain_bottom_while(|_| true).is_empty());
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn drain_bottom_while_prefix() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 5, 3, 4]);
+        assert_eq!(stack.drain_bottom_while(|value| *value < 5), vec![1, 2]);
+        assert_eq!(stack.to_list(), vec![4, 3, 5]);
+    }
+
+    #[test]
+    fn drain_bottom_while_all() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        assert_eq!(stack.drain_bottom_while(|_| true), vec![1, 2, 3]);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn drain_bottom_while_none() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        assert!(stack.drain_bottom_while(|value| *value > 1).is_empty());
+        assert_eq!(stack.to_list(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn drain_bottom_while_stops_calling_predicate_at_first_failure() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3, 4]);
+        let mut visited = Vec::new();
+        stack.drain_bottom_while(|value| {
+            visited.push(*value);
+            *value < 2
+        });
+        assert_eq!(visited, vec![1, 2]);
+    }
+}
+
+#[cfg(test)]
+mod checked_peek_tests {
+    use super::*;
+
+    #[test]
+    fn checked_peek_on_filled_stack() {
+        let mut stack = Stack::empty();
+        stack.push(1);
+        stack.push(2);
+        assert_eq!(stack.checked_peek(), Ok(&2));
+        assert_eq!(stack.to_list(), vec![2, 1]);
+    }
+
+    #[test]
+    fn checked_peek_on_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert_eq!(stack.checked_peek(), Err(EmptyStackError));
+    }
+
+    #[test]
+    fn checked_peek_returns_reference_to_head_value() {
+        let stack = Stack::new(String::from("head"));
+        assert!(std::ptr::eq(stack.checked_peek().unwrap(), &stack[0]));
+    }
+
+    #[test]
+    fn checked_peek_with_question_mark() {
+        fn peek_len(stack: &Stack<String>) -> Result<usize, Box<dyn Error>> {
+            Ok(stack.checked_peek()?.len())
+        }
+
+        assert_eq!(peek_len(&Stack::new(String::from("abc"))).unwrap(), 3);
+        let error = peek_len(&Stack::empty()).unwrap_err();
+        assert_eq!(error.to_string(), "stack is empty");
+    }
+}
+
+#[cfg(test)]
+mod cycle_take_tests {
+    use super::test_support::stack_of;
+    use super::*;
+
+    #[test]
+    fn cycle_take_from_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert!(stack.cycle_take(5).is_empty());
+    }
+
+    #[test]
+    fn cycle_take_zero() {
+        assert!(stack_of([1, 2, 3]).cycle_take(0).is_empty());
+    }
+
+    #[test]
+    fn cycle_take_less_than_size() {
+        assert_eq!(stack_of([1, 2, 3]).cycle_take(2), vec![3, 2]);
+    }
+
+    #[test]
+    fn cycle_take_equal_to_size() {
+        assert_eq!(stack_of([1, 2, 3]).cycle_take(3), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn cycle_take_greater_than_size() {
+        assert_eq!(stack_of([1, 2, 3]).cycle_take(7), vec![3, 2, 1, 3, 2, 1, 3]);
+    }
+}
+
+#[cfg(test)]
+mod iter_tests {
+    use super::*;
+
+    #[test]
+    fn iter_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert_eq!(stack.iter().next(), None);
+        assert_eq!(stack.iter_peekable().peek(), None);
+    }
+
+    #[test]
+    fn iter_from_head() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        let values: Vec<_> = stack.iter().copied().collect();
+        assert_eq!(values, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn iter_clone_continues_from_same_position() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        let mut values = stack.iter();
+        values.next();
+
+        let rest: Vec<_> = values.clone().collect();
+        assert_eq!(rest, vec![&2, &1]);
+        assert_eq!(values.next(), Some(&2));
+    }
+
+    #[test]
+    fn iter_peekable_peek_then_advance() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        let mut values = stack.iter_peekable();
+
+        assert_eq!(values.peek(), Some(&&3));
+        assert_eq!(values.peek(), Some(&&3));
+        assert_eq!(values.next(), Some(&3));
+        assert_eq!(values.next_if(|value| **value == 1), None);
+        assert_eq!(values.next_if(|value| **value == 2), Some(&2));
+        assert_eq!(values.peek(), Some(&&1));
+        assert_eq!(values.next(), Some(&1));
+        assert_eq!(values.peek(), None);
+        assert_eq!(values.next(), None);
+    }
+}
+
+#[cfg(test)]
+mod split_every_tests {
+    use super::*;
+
+    fn lists(stacks: Vec<Stack<u32>>) -> Vec<Vec<u32>> {
+        stacks.iter().map(Stack::to_list).collect()
+    }
+
+    #[test]
+    fn split_every_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert!(stack.split_every(2).is_empty());
+    }
 
-        let boolean_stack = Stack::new(false);
-        assert_eq!(boolean_stack.peek(), Some(false));
+    #[test]
+    fn split_every_evenly_divisible() {
+        let mut stack = Stack::empty();
+        stack.push_many(1..=6);
+        assert_eq!(
+            lists(stack.split_every(3)),
+            vec![vec![6, 5, 4], vec![3, 2, 1]]
+        );
+    }
 
-        let str_stack = Stack::new("asd");
-        assert_eq!(str_stack.peek(), Some("asd"));
+    #[test]
+    fn split_every_with_remainder() {
+        let mut stack = Stack::empty();
+        stack.push_many(1..=5);
+        assert_eq!(
+            lists(stack.split_every(2)),
+            vec![vec![5, 4], vec![3, 2], vec![1]]
+        );
     }
 
     #[test]
-    fn complex_stack() {
-        #[allow(dead_code)]
-        #[derive(Clone, PartialEq, Debug)]
-        struct Point {
-            x: u32,
-            y: u32,
-        }
-        let point_stack = Stack::new(Point { x: 1, y: 2 });
-        assert_eq!(point_stack.peek(), Some(Point { x: 1, y: 2 }));
+    fn split_every_with_size_larger_than_stack() {
+        let mut stack = Stack::empty();
+        stack.push_many(1..=3);
+        assert_eq!(lists(stack.split_every(10)), vec![vec![3, 2, 1]]);
+    }
+
+    #[test]
+    fn split_every_sub_stacks_pop_from_head() {
+        let mut stack = Stack::empty();
+        stack.push_many(1..=4);
+        let mut stacks = stack.split_every(2);
+        assert_eq!(stacks[0].pop(), Some(4));
+        assert_eq!(stacks[1].pop(), Some(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be greater than zero")]
+    fn split_every_with_zero_size() {
+        Stack::new(1).split_every(0);
     }
 }
 
 #[cfg(test)]
-mod is_empty_tests {
+mod interleave_tests {
     use super::*;
 
     #[test]
-    fn is_empty_with_empty_stack() {
+    fn interleave_empty_stacks() {
         let stack: Stack<u32> = Stack::empty();
-        assert!(stack.is_empty());
+        assert!(stack.interleave(Stack::empty()).is_empty());
     }
 
     #[test]
-    fn is_empty_with_filled_stack() {
-        let stack = Stack::new(1);
-        assert!(!stack.is_empty());
+    fn interleave_with_one_empty_stack() {
+        assert_eq!(
+            Stack::from_reversed_vec(vec![1, 2])
+                .interleave(Stack::empty())
+                .to_list(),
+            vec![1, 2]
+        );
+        assert_eq!(
+            Stack::empty()
+                .interleave(Stack::from_reversed_vec(vec![1, 2]))
+                .to_list(),
+            vec![1, 2]
+        );
+    }
+
+    #[test]
+    fn interleave_equal_lengths() {
+        let interleaved = Stack::from_reversed_vec(vec![1, 3, 5])
+            .interleave(Stack::from_reversed_vec(vec![2, 4, 6]));
+        assert_eq!(interleaved.to_list(), vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn interleave_with_longer_first_stack() {
+        let interleaved = Stack::from_reversed_vec(vec![1, 3, 5, 6])
+            .interleave(Stack::from_reversed_vec(vec![2, 4]));
+        assert_eq!(interleaved.to_list(), vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn interleave_with_longer_second_stack() {
+        let interleaved = Stack::from_reversed_vec(vec![1, 3])
+            .interleave(Stack::from_reversed_vec(vec![2, 4, 5, 6]));
+        assert_eq!(interleaved.to_list(), vec![1, 2, 3, 4, 5, 6]);
     }
 }
 
 #[cfg(test)]
-mod peek_tests {
+mod retain_mut_tests {
+    use super::test_support::CloneCounter;
     use super::*;
+    use std::cell::Cell;
 
     #[test]
-    fn peek_empty_stack() {
-        let empty_stack: Stack<u32> = Stack::empty();
-        assert_eq!(empty_stack.peek(), None);
+    fn retain_mut_empty_stack() {
+        let mut stack: Stack<u32> = Stack::empty();
+        stack.retain_mut(|_| true);
+        assert!(stack.is_empty());
     }
 
     #[test]
-    fn peek_filled_stack() {
-        let stack = Stack::new(1);
-        assert_eq!(stack.peek(), Some(1));
-        assert_eq!(stack.head.unwrap().value, 1);
+    fn retain_mut_edits_kept_values() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3, 4, 5]);
+        stack.retain_mut(|value| {
+            if *value % 2 == 1 {
+                *value += 100;
+                true
+            } else {
+                *value = 0;
+                false
+            }
+        });
+        assert_eq!(stack.to_list(), vec![105, 103, 101]);
+        assert_eq!(stack.len(), 3);
     }
 
     #[test]
-    fn peek_filled_stack_multiple_times() {
-        let stack = Stack::new(1);
-        assert_eq!(stack.peek(), Some(1));
-        assert_eq!(stack.peek(), Some(1));
-        assert_eq!(stack.peek(), Some(1));
-        assert_eq!(stack.head.unwrap().value, 1);
+    fn retain_mut_drops_all_values() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        stack.retain_mut(|value| {
+            *value += 1;
+            false
+        });
+        assert!(stack.is_empty());
     }
 
     #[test]
-    fn reference_on_peek_is_unchanged() {
-        let node = Rc::new(Node {
-            value: 100,
-            next: None,
+    fn retain_mut_visits_values_from_head() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        let mut visited = Vec::new();
+        stack.retain_mut(|value| {
+            visited.push(*value);
+            true
         });
-        assert_eq!(Rc::strong_count(&node), 1); // node itself
-
-        {
-            let stack = Stack {
-                head: Some(Rc::clone(&node)),
-            };
-            assert_eq!(Rc::strong_count(&node), 2); // node itself, and referenced by stack
+        assert_eq!(visited, vec![3, 2, 1]);
+    }
 
-            let peek_result = stack.peek();
-            assert_eq!(peek_result, Some(100));
+    #[test]
+    fn retain_mut_on_shared_stack() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        let cloned_stack = stack.clone();
+        stack.retain_mut(|value| {
+            *value *= 2;
+            *value != 4
+        });
+        assert_eq!(stack.to_list(), vec![6, 2]);
+        assert_eq!(cloned_stack.to_list(), vec![3, 2, 1]);
+    }
 
-            assert_eq!(Rc::strong_count(&node), 2); // peek should not modify strong count
+    #[test]
+    fn retain_mut_clones_only_shared_values() {
+        let clones = Cell::new(0);
+        let mut stack = Stack::empty();
+        for value in 1..=3 {
+            stack.push(CloneCounter::new(value, &clones));
         }
-        // stack is destroyed here
+        let cloned_stack = stack.clone();
+        stack.push(CloneCounter::new(4, &clones));
+        stack.push(CloneCounter::new(5, &clones));
 
-        assert_eq!(Rc::strong_count(&node), 1); // node itself
+        stack.retain_mut(|counter| counter.value % 2 == 1);
+        assert_eq!(clones.get(), 3); // the 3 values shared with cloned_stack
+        let values: Vec<u32> = stack.iter().map(|counter| counter.value).collect();
+        assert_eq!(values, vec![5, 3, 1]);
+        assert_eq!(cloned_stack.len(), 3);
     }
 }
 
 #[cfg(test)]
-mod size_tests {
+mod stats_tests {
     use super::*;
 
     #[test]
-    fn size_of_empty_stack() {
-        let stack: Stack<u32> = Stack::empty();
-        assert_eq!(stack.size(), 0);
+    fn stats_of_empty_stack() {
+        let stack: Stack<u64> = Stack::empty();
+        assert_eq!(
+            stack.stats(),
+            StackStats {
+                len: 0,
+                approx_bytes: 0,
+            }
+        );
     }
 
     #[test]
-    fn size_of_single_stack() {
-        let stack = Stack::new(100);
-        assert_eq!(stack.size(), 1);
+    fn stats_len_matches_stack_len() {
+        let mut stack = Stack::empty();
+        stack.push_many(1..=5_u64);
+        assert_eq!(stack.stats().len, stack.len());
     }
 
     #[test]
-    fn size_of_filled_stack() {
-        let stack = Stack {
-            head: Some(Rc::new(Node {
-                value: 100,
-                next: Some(Rc::new(Node {
-                    value: 200,
-                    next: Some(Rc::new(Node {
-                        value: 300,
-                        next: None,
-                    })),
-                })),
-            })),
-        };
-        assert_eq!(stack.size(), 3);
+    fn stats_bytes_cover_every_node() {
+        let stack = Stack::new(1_u64);
+        assert!(stack.stats().approx_bytes >= size_of::<Node<u64>>());
+    }
+
+    #[test]
+    fn stats_bytes_scale_linearly() {
+        let node_bytes = Stack::new(1_u64).stats().approx_bytes;
+        for len in [2, 10, 100] {
+            let stack = Stack::from_fn(len, |index| index as u64);
+            assert_eq!(stack.stats().approx_bytes, len * node_bytes);
+        }
     }
 }
 
 #[cfg(test)]
-mod list_tests {
+mod try_map_tests {
     use super::*;
 
+    fn halve(value: &u32) -> Result<u32, String> {
+        if value.is_multiple_of(2) {
+            Ok(value / 2)
+        } else {
+            Err(format!("{value} is odd"))
+        }
+    }
+
     #[test]
-    fn list_empty_stack() {
+    fn try_map_empty_stack() {
         let stack: Stack<u32> = Stack::empty();
-        assert_eq!(stack.to_list(), Vec::<u32>::new());
+        assert!(stack.try_map(halve).unwrap().is_empty());
     }
 
     #[test]
-    fn list_filled_stack() {
-        let stack = Stack {
-            head: Some(Rc::new(Node {
-                value: 1,
-                next: Some(Rc::new(Node {
-                    value: 2,
-                    next: Some(Rc::new(Node {
-                        value: 3,
-                        next: None,
-                    })),
-                })),
-            })),
-        };
-        assert_eq!(stack.to_list(), vec![1, 2, 3]);
+    fn try_map_all_succeed() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![2, 4, 6]);
+        assert_eq!(stack.try_map(halve).unwrap().to_list(), vec![3, 2, 1]);
+        assert_eq!(stack.to_list(), vec![6, 4, 2]);
+    }
+
+    #[test]
+    fn try_map_returns_first_error() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![2, 5, 4, 3, 6]);
+        assert_eq!(stack.try_map(halve).err(), Some(String::from("3 is odd")));
+    }
+
+    #[test]
+    fn try_map_stops_at_first_error() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![2, 1, 4]);
+        let calls = std::cell::Cell::new(0);
+        let result = stack.try_map(|value| {
+            calls.set(calls.get() + 1);
+            halve(value)
+        });
+        assert!(result.is_err());
+        assert_eq!(calls.get(), 2);
     }
 }
 
 #[cfg(test)]
-mod push_tests {
+mod window_fold_tests {
     use super::*;
 
+    fn sum(window: &[u32]) -> u32 {
+        window.iter().sum()
+    }
+
     #[test]
-    fn push_once_to_empty_stack() {
+    fn window_fold_empty_stack() {
+        let stack: Stack<u32> = Stack::empty();
+        assert!(stack.window_fold(1, sum).is_empty());
+    }
+
+    #[test]
+    fn window_fold_sums_of_size_two() {
         let mut stack = Stack::empty();
-        stack.push(1);
-        assert_eq!(stack.size(), 1);
-        assert_eq!(stack.to_list(), vec![1]);
+        stack.push_many(vec![1, 2, 3, 4, 5]);
+        assert_eq!(stack.window_fold(2, sum), vec![9, 7, 5, 3]);
     }
 
     #[test]
-    fn push_once_to_filled_stack() {
-        let mut stack = Stack::new(1);
-        stack.push(2);
-        assert_eq!(stack.size(), 2);
-        assert_eq!(stack.to_list(), vec![2, 1]);
+    fn window_fold_sums_of_size_three() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3, 4, 5]);
+        assert_eq!(stack.window_fold(3, sum), vec![12, 9, 6]);
     }
 
     #[test]
-    fn push_many_times() {
+    fn window_fold_orders_window_from_head() {
         let mut stack = Stack::empty();
-        assert_eq!(stack.size(), 0);
-        assert_eq!(stack.to_list(), vec![]);
+        stack.push_many(vec![1, 2, 3]);
+        assert_eq!(
+            stack.window_fold(2, |window| window.to_vec()),
+            vec![vec![3, 2], vec![2, 1]]
+        );
+    }
 
-        stack.push(1);
-        assert_eq!(stack.size(), 1);
-        assert_eq!(stack.to_list(), vec![1]);
+    #[test]
+    fn window_fold_with_size_equal_to_stack() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        assert_eq!(stack.window_fold(3, sum), vec![6]);
+    }
 
-        stack.push(2);
-        assert_eq!(stack.size(), 2);
-        assert_eq!(stack.to_list(), vec![2, 1]);
+    #[test]
+    fn window_fold_with_size_larger_than_stack() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2]);
+        assert!(stack.window_fold(3, sum).is_empty());
+    }
 
-        stack.push(3);
-        assert_eq!(stack.size(), 3);
-        assert_eq!(stack.to_list(), vec![3, 2, 1]);
+    #[test]
+    #[should_panic(expected = "window size must be greater than zero")]
+    fn window_fold_with_zero_size() {
+        Stack::new(1).window_fold(0, sum);
     }
 }
 
 #[cfg(test)]
-mod pop_tests {
+mod pop_all_matching_tests {
     use super::*;
 
     #[test]
-    fn pop_on_empty_stack() {
+    fn pop_all_matching_empty_stack() {
         let mut stack: Stack<u32> = Stack::empty();
-        let result = stack.pop();
-        assert_eq!(result, None);
-        assert_eq!(stack.size(), 0);
+        assert_eq!(stack.pop_all_matching(|_| true), 0);
+        assert!(stack.is_empty());
     }
 
     #[test]
-    fn pop_on_stack_with_one_element() {
-        let mut stack = Stack::new(1);
-        let result = stack.pop();
-        assert_eq!(result, Some(1));
-        assert_eq!(stack.size(), 0);
+    fn pop_all_matching_prefix() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 0, 2, 0, 0]);
+        assert_eq!(stack.pop_all_matching(|value| *value == 0), 2);
+        assert_eq!(stack.to_list(), vec![2, 0, 1]);
     }
 
     #[test]
-    fn pop_on_stack_with_several_element() {
+    fn pop_all_matching_everything() {
         let mut stack = Stack::empty();
-        stack.push(100);
-        stack.push(200);
-        stack.push(300);
+        stack.push_many(vec![1, 2, 3]);
+        assert_eq!(stack.pop_all_matching(|_| true), 3);
+        assert!(stack.is_empty());
+    }
 
-        assert_eq!(stack.size(), 3);
+    #[test]
+    fn pop_all_matching_nothing() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        assert_eq!(stack.pop_all_matching(|value| *value == 1), 0);
+        assert_eq!(stack.to_list(), vec![3, 2, 1]);
+    }
 
-        assert_eq!(stack.pop(), Some(300));
-        assert_eq!(stack.size(), 2);
+    #[test]
+    fn pop_all_matching_on_shared_stack() {
+        let mut stack = Stack::empty();
+        stack.push_many(vec![1, 2, 3]);
+        let cloned_stack = stack.clone();
+        assert_eq!(stack.pop_all_matching(|value| *value > 1), 2);
+        assert_eq!(stack.to_list(), vec![1]);
+        assert_eq!(cloned_stack.to_list(), vec![3, 2, 1]);
+    }
+}
 
-        assert_eq!(stack.pop(), Some(200));
-        assert_eq!(stack.size(), 1);
+#[cfg(test)]
+mod pointer_tests {
+    use super::*;
+    use crate::pointer::Arc;
 
-        assert_eq!(stack.pop(), Some(100));
-        assert_eq!(stack.size(), 0);
+    fn assert_send_sync<T: Send + Sync>() {}
 
-        assert_eq!(stack.pop(), None);
-        assert_eq!(stack.size(), 0);
+    fn check_operations<P: Pointer>() {
+        let mut stack: Stack<u32, P> = Stack::default();
+        assert!(stack.is_empty());
+        stack.push_many(vec![1, 2, 3]);
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.peek(), Some(3));
+        assert_eq!(stack[2], 1);
 
-        assert_eq!(stack.pop(), None);
-        assert_eq!(stack.size(), 0);
+        let mut cloned_stack = stack.clone();
+        cloned_stack.with_each_mut(|value| *value *= 10);
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop_back(), Some(1));
+        assert_eq!(stack.to_list(), vec![2]);
+        assert_eq!(cloned_stack.to_list(), vec![30, 20, 10]);
 
-        assert_eq!(stack.pop(), None);
-        assert_eq!(stack.size(), 0);
+        cloned_stack.retain_mut(|value| *value != 20);
+        assert_eq!(cloned_stack.into_list(), vec![30, 10]);
     }
 
     #[test]
-    fn reference_on_pop() {
-        let node = Rc::new(Node {
-            value: 100,
-            next: None,
-        });
-        assert_eq!(Rc::strong_count(&node), 1); // node itself
+    fn rc_stack_operations() {
+        check_operations::<pointer::Rc>();
+    }
 
-        {
-            let mut stack = Stack {
-                head: Some(Rc::clone(&node)),
-            };
-            assert_eq!(Rc::strong_count(&node), 2); // node itself, and referenced by stack
+    #[test]
+    fn arc_stack_operations() {
+        check_operations::<Arc>();
+    }
 
-            let pop_result = stack.pop();
-            assert_eq!(pop_result, Some(100));
+    #[test]
+    fn default_pointer_is_rc() {
+        let stack: Stack<u32, pointer::Rc> = Stack::new(1);
+        assert_eq!(stack.head_strong_count(), 1);
+    }
 
-            // because stack.head does not reference to node anymore, the count decreased
-            assert_eq!(Rc::strong_count(&node), 1); // node itself
-        }
-        // stack is destroyed here
+    #[test]
+    fn arc_stack_is_send_and_sync() {
+        assert_send_sync::<Stack<u32, Arc>>();
+        assert_send_sync::<StackSnapshot<u32, Arc>>();
+    }
 
-        assert_eq!(Rc::strong_count(&node), 1); // node itself
+    #[test]
+    fn arc_stack_shared_across_threads() {
+        let mut stack: Stack<u32, Arc> = Stack::default();
+        stack.push_many(vec![1, 2, 3]);
+
+        let cloned_stack = stack.clone();
+        let handle = std::thread::spawn(move || {
+            let mut cloned_stack = cloned_stack;
+            cloned_stack.push(4);
+            cloned_stack.to_list()
+        });
+        assert_eq!(handle.join().unwrap(), vec![4, 3, 2, 1]);
+        assert_eq!(stack.to_list(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn arc_stack_bytes_round_trip() {
+        let mut stack: Stack<u32, Arc> = Stack::default();
+        stack.push_many(vec![1, 2, u32::MAX]);
+
+        let decoded = Stack::<u32, Arc>::from_bytes(&stack.to_bytes()).unwrap();
+        assert_eq!(decoded.to_list(), vec![u32::MAX, 2, 1]);
+        assert_send_sync::<Stack<u32, Arc>>();
+    }
+
+    #[test]
+    fn arc_stack_from_str() {
+        let stack: Stack<char, Arc> = "abc".parse().unwrap();
+        assert_eq!(stack.to_string_top_first(), "cba");
     }
 }